@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use leptos_scholar::{extract_citations, parse_author_info, FetchOptions};
+use scraper::Html;
+
+/// A profile with 50 histogram years, 20 co-authors and 100 publications
+const LARGE_PROFILE: &str = include_str!("fixtures/large_profile.html");
+
+fn parsing(c: &mut Criterion) {
+    let document = Html::parse_document(LARGE_PROFILE);
+    let options = FetchOptions::default();
+
+    let mut group = c.benchmark_group("large_profile");
+    group.throughput(Throughput::Bytes(LARGE_PROFILE.len() as u64));
+
+    group.bench_function("parse_document", |b| {
+        b.iter(|| Html::parse_document(black_box(LARGE_PROFILE)))
+    });
+    group.bench_function("parse_author_info", |b| {
+        b.iter(|| parse_author_info(black_box(&document), &options).unwrap())
+    });
+    group.bench_function("extract_citations", |b| {
+        b.iter(|| extract_citations(black_box(&document)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parsing);
+criterion_main!(benches);
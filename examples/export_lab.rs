@@ -0,0 +1,104 @@
+//! Exports the profiles of a lab to a directory
+//!
+//! Reads Google Scholar IDs from a file (one per line, `#` starts a comment),
+//! fetches them a few at a time and writes `<id>.yaml` per author plus a
+//! `metrics.csv` with the summary metrics of all of them. Failures don't stop
+//! the export, they are listed at the end.
+//!
+//! ```text
+//! cargo run --example export_lab -- lab.txt export/ [concurrency]
+//! ```
+//!
+//! The environment variables of [`options_from_env`] (e.g. `SCHOLAR_PROXY`)
+//! are honored.
+
+use anyhow::{Context, Result};
+use futures::StreamExt;
+use leptos_scholar::{fetch_many, options_from_env, AuthorInfo, OutputFormat};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Number of profiles fetched at the same time unless given on the command line
+const DEFAULT_CONCURRENCY: usize = 2;
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let (Some(ids_file), Some(output_dir)) = (args.next(), args.next()) else {
+        anyhow::bail!("Usage: export_lab <ids file> <output directory> [concurrency]");
+    };
+    let concurrency = match args.next() {
+        Some(concurrency) => concurrency.parse().context("Invalid concurrency")?,
+        None => DEFAULT_CONCURRENCY,
+    };
+
+    let author_ids = read_author_ids(Path::new(&ids_file))?;
+    let output_dir = PathBuf::from(output_dir);
+    fs::create_dir_all(&output_dir)?;
+
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(export(&author_ids, &output_dir, concurrency))
+}
+
+/// Fetches all authors, writing each profile as soon as it arrives
+async fn export(author_ids: &[String], output_dir: &Path, concurrency: usize) -> Result<()> {
+    let options = options_from_env();
+    let mut csv = fs::File::create(output_dir.join("metrics.csv"))?;
+    writeln!(csv, "author_id,name,citations,h_index,i10_index")?;
+
+    let mut failures = Vec::new();
+    let mut results = std::pin::pin!(fetch_many(author_ids, concurrency, &options));
+    while let Some(batch_result) = results.next().await {
+        let author_id = batch_result.author_id;
+        match batch_result.result {
+            Ok(info) => {
+                let yaml = OutputFormat::Yaml.serialize(&info)?;
+                fs::write(output_dir.join(format!("{author_id}.yaml")), yaml)?;
+                write_csv_row(&mut csv, &author_id, &info)?;
+                println!("{author_id}: {}", info.name);
+            }
+            Err(e) => {
+                eprintln!("{author_id}: {e}");
+                failures.push((author_id, e));
+            }
+        }
+    }
+
+    println!(
+        "Exported {} of {} profiles to {}",
+        author_ids.len() - failures.len(),
+        author_ids.len(),
+        output_dir.display()
+    );
+    for (author_id, e) in &failures {
+        println!("  failed: {author_id} ({e})");
+    }
+    Ok(())
+}
+
+/// Reads the IDs of a file, skipping blank lines and comments
+fn read_author_ids(path: &Path) -> Result<Vec<String>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Can't read {}", path.display()))?;
+
+    Ok(content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Appends the summary metrics of an author to the CSV
+fn write_csv_row(csv: &mut impl Write, author_id: &str, info: &AuthorInfo) -> Result<()> {
+    // Names may contain commas or quotes, so they are always quoted
+    let name = info.name.replace('"', "\"\"");
+    writeln!(
+        csv,
+        "{author_id},\"{name}\",{},{},{}",
+        info.total, info.h_index, info.i10_index
+    )?;
+    Ok(())
+}
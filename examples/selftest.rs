@@ -0,0 +1,46 @@
+//! Checks the crate's selectors against live Google Scholar pages
+//!
+//! Prints one line per selector role and exits with status 1 if any of them
+//! didn't match (or 2 if Scholar couldn't be reached), so it can run as a
+//! cron canary for layout changes.
+//!
+//! ```text
+//! cargo run --example selftest
+//! ```
+//!
+//! The environment variables of [`options_from_env`] (e.g. `SCHOLAR_PROXY`)
+//! are honored.
+
+use leptos_scholar::{options_from_env, selftest_with_options};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Can't start the runtime: {e}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match runtime.block_on(selftest_with_options(&options_from_env())) {
+        Ok(report) => {
+            print!("{report}");
+            let failures = report.failures().count();
+            if failures == 0 {
+                println!("All selectors matched");
+                ExitCode::SUCCESS
+            } else {
+                eprintln!("{failures} selector roles didn't match");
+                ExitCode::FAILURE
+            }
+        }
+        Err(e) => {
+            eprintln!("Self-test failed: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Watches a profile and prints what changed at every poll
+//!
+//! Polls the profile every `--interval` seconds (by default the
+//! [`recommended_poll_interval`]) and prints the changes since the last poll,
+//! e.g. "citations: 1200 -> 1234 (+34)". The last snapshot is kept in
+//! `--snapshot` (by default `<id>.json`), so a restarted watcher reports the
+//! changes since it stopped. Runs until interrupted.
+//!
+//! ```text
+//! cargo run --example watch -- <id> [--interval <seconds>] [--snapshot <file>]
+//! ```
+//!
+//! The environment variables of [`options_from_env`] (e.g. `SCHOLAR_PROXY`)
+//! are honored.
+
+use anyhow::{Context, Result};
+use leptos_scholar::{options_from_env, recommended_poll_interval, watch, AuthorId};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: watch <id> [--interval <seconds>] [--snapshot <file>]";
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let author_id: AuthorId = args
+        .next()
+        .context(USAGE)?
+        .parse()
+        .context("Invalid Google Scholar ID")?;
+    let mut interval = recommended_poll_interval();
+    let mut snapshot = PathBuf::from(format!("{author_id}.json"));
+    while let Some(flag) = args.next() {
+        let value = args.next().context(USAGE)?;
+        match flag.as_str() {
+            "--interval" => {
+                interval = Duration::from_secs(value.parse().context("Invalid interval")?)
+            }
+            "--snapshot" => snapshot = PathBuf::from(value),
+            _ => anyhow::bail!(USAGE),
+        }
+    }
+
+    println!(
+        "Watching {author_id} every {}s, snapshot in {}",
+        interval.as_secs(),
+        snapshot.display()
+    );
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(watch(
+            &author_id,
+            interval,
+            &snapshot,
+            &options_from_env(),
+            |diff| print!("{diff}"),
+        ))
+}
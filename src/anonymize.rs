@@ -0,0 +1,183 @@
+use crate::{profile_url, AuthorInfo, Coauthor, Publication};
+use siphasher::sip::SipHasher24;
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use url::Url;
+
+/// Replaces everything that identifies people in a profile with pseudonyms
+///
+/// Names, the affiliation, email domains, co-author IDs and publication
+/// titles become pseudonyms derived from a stable hash of the original, so
+/// the same person gets the same pseudonym in every profile and every run
+/// (e.g. a co-author and the author of their own profile). The photos,
+/// homepage, citation links, publication links and the extra sidebar
+/// sections are removed, and the co-author links point to their pseudonymous
+/// ID. Metrics, the histogram and the publication venues, years and counts
+/// are kept.
+///
+/// The hash is not keyed, so anyone with a list of candidate names (e.g. the
+/// co-authors listed on Scholar) can hash them and match the pseudonyms. It
+/// only keeps names out of plain sight; use [`anonymize_with_key`] for data
+/// that leaves your hands.
+///
+/// # Arguments
+///
+/// * `info` - The profile to anonymize
+///
+/// # Returns
+///
+/// * `AuthorInfo` - A copy without identifying data
+pub fn anonymize(info: &AuthorInfo) -> AuthorInfo {
+    anonymize_profile(info, None)
+}
+
+/// Replaces everything that identifies people in a profile with keyed pseudonyms
+///
+/// Like [`anonymize`], but the pseudonyms are a keyed hash (SipHash-2-4) of
+/// the original, so they can't be matched against candidate names without
+/// the key. The same key gives the same pseudonyms, so keep it secret and
+/// reuse it for profiles that should stay linkable, e.g. drawn once with
+/// `getrandom` and stored next to the data.
+///
+/// # Arguments
+///
+/// * `info` - The profile to anonymize
+/// * `key` - The secret key of the hash
+///
+/// # Returns
+///
+/// * `AuthorInfo` - A copy without identifying data
+pub fn anonymize_with_key(info: &AuthorInfo, key: &[u8; 16]) -> AuthorInfo {
+    anonymize_profile(info, Some(key))
+}
+
+/// Anonymizes a profile with pseudonyms keyed by `key`, unkeyed if `None`
+fn anonymize_profile(info: &AuthorInfo, key: Option<&[u8; 16]>) -> AuthorInfo {
+    // Destructured, so a new field can't slip through without a decision
+    let AuthorInfo {
+        name,
+        total,
+        h_index,
+        i10_index,
+        raw_metrics,
+        citation_links: _,
+        yearly_citations,
+        affiliation,
+        verified_email_domains,
+        verified,
+        photo_url: _,
+        homepage: _,
+        coauthors,
+        publications,
+        publications_truncated,
+        extra_metrics: _,
+        sources,
+        warnings,
+    } = info;
+
+    AuthorInfo {
+        name: pseudonym("author", name, key),
+        total: *total,
+        h_index: *h_index,
+        i10_index: *i10_index,
+        raw_metrics: raw_metrics.clone(),
+        // The links contain the profile ID
+        citation_links: None,
+        yearly_citations: yearly_citations.clone(),
+        affiliation: affiliation
+            .as_deref()
+            .map(|affiliation| pseudonym("affiliation", affiliation, key)),
+        verified_email_domains: verified_email_domains
+            .iter()
+            .map(|domain| pseudonym("domain", domain, key))
+            .collect(),
+        verified: *verified,
+        photo_url: None,
+        homepage: None,
+        coauthors: coauthors
+            .iter()
+            .map(|coauthor| anonymize_coauthor(coauthor, key))
+            .collect(),
+        publications: publications
+            .iter()
+            .map(|publication| anonymize_publication(publication, key))
+            .collect(),
+        publications_truncated: *publications_truncated,
+        // Free text of the sidebar, which may name people or institutions
+        extra_metrics: BTreeMap::new(),
+        sources: sources.clone(),
+        warnings: warnings.clone(),
+    }
+}
+
+/// Replaces the name, affiliation and ID of a co-author
+fn anonymize_coauthor(coauthor: &Coauthor, key: Option<&[u8; 16]>) -> Coauthor {
+    let author_id = pseudonym("id", &coauthor.author_id, key);
+    Coauthor {
+        url: Url::parse(&profile_url(&author_id)).unwrap_or_else(|_| coauthor.url.clone()),
+        author_id,
+        name: pseudonym("author", &coauthor.name, key),
+        affiliation: coauthor
+            .affiliation
+            .as_deref()
+            .map(|affiliation| pseudonym("affiliation", affiliation, key)),
+        photo_url: None,
+    }
+}
+
+/// Replaces the title and authors of a publication and drops its links
+///
+/// The title would find the publication, and with it the author, in any
+/// search engine. The links contain the profile ID.
+fn anonymize_publication(publication: &Publication, key: Option<&[u8; 16]>) -> Publication {
+    let Publication {
+        title,
+        authors,
+        venue,
+        year,
+        citations,
+        cite_id,
+        url: _,
+        cited_by_url: _,
+    } = publication;
+
+    Publication {
+        title: pseudonym("title", title, key),
+        authors: authors
+            .iter()
+            .map(|author| pseudonym("author", author, key))
+            .collect(),
+        venue: venue.clone(),
+        year: *year,
+        citations: *citations,
+        cite_id: cite_id
+            .as_deref()
+            .map(|cite_id| pseudonym("cite", cite_id, key)),
+        url: None,
+        cited_by_url: None,
+    }
+}
+
+/// Builds a pseudonym like `author-1f0c2a9be3d47c85` from a kind and the original value
+fn pseudonym(kind: &str, value: &str, key: Option<&[u8; 16]>) -> String {
+    let bytes = value.trim().as_bytes();
+    let hash = match key {
+        Some(key) => {
+            let mut hasher = SipHasher24::new_with_key(key);
+            hasher.write(bytes);
+            hasher.finish()
+        }
+        None => fnv1a(bytes),
+    };
+    format!("{kind}-{hash:016x}")
+}
+
+/// 64-bit FNV-1a hash, which unlike `std`'s hasher is fixed across Rust versions
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
@@ -0,0 +1,121 @@
+use crate::ScraperError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Length of a Google Scholar author ID
+const AUTHOR_ID_LENGTH: usize = 12;
+
+/// Checks whether a string has the form of a Google Scholar author ID
+///
+/// IDs are 12 characters of letters, digits, `-` and `_`, e.g.
+/// [`EXAMPLE_AUTHOR_ID`](crate::EXAMPLE_AUTHOR_ID). A valid form doesn't mean
+/// the profile exists, see [`check_ids`](crate::check_ids) for that.
+///
+/// # Arguments
+///
+/// * `author_id` - The string to check
+///
+/// # Returns
+///
+/// * `bool` - Whether the string is a well-formed author ID
+pub fn is_valid_author_id(author_id: &str) -> bool {
+    author_id.len() == AUTHOR_ID_LENGTH
+        && author_id
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_')
+}
+
+/// A well-formed Google Scholar author ID
+///
+/// All functions taking an ID accept it as well as plain strings (`&str`,
+/// `&String` or `String`), which they validate the same way before sending any request.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AuthorId(String);
+
+impl AuthorId {
+    /// Returns the ID as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for AuthorId {
+    type Error = ScraperError;
+
+    /// Accepts the ID if it is well-formed, see [`is_valid_author_id`]
+    fn try_from(author_id: &str) -> Result<Self, Self::Error> {
+        author_id.to_string().try_into()
+    }
+}
+
+impl TryFrom<&String> for AuthorId {
+    type Error = ScraperError;
+
+    /// Accepts the ID if it is well-formed, see [`is_valid_author_id`]
+    fn try_from(author_id: &String) -> Result<Self, Self::Error> {
+        author_id.as_str().try_into()
+    }
+}
+
+impl TryFrom<String> for AuthorId {
+    type Error = ScraperError;
+
+    /// Accepts the ID if it is well-formed, see [`is_valid_author_id`]
+    fn try_from(author_id: String) -> Result<Self, Self::Error> {
+        if is_valid_author_id(&author_id) {
+            Ok(Self(author_id))
+        } else {
+            Err(ScraperError::InvalidId)
+        }
+    }
+}
+
+impl From<&AuthorId> for AuthorId {
+    fn from(author_id: &AuthorId) -> Self {
+        author_id.clone()
+    }
+}
+
+impl FromStr for AuthorId {
+    type Err = ScraperError;
+
+    fn from_str(author_id: &str) -> Result<Self, Self::Err> {
+        author_id.try_into()
+    }
+}
+
+impl From<AuthorId> for String {
+    fn from(author_id: AuthorId) -> Self {
+        author_id.0
+    }
+}
+
+impl Deref for AuthorId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for AuthorId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AuthorId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Converts the ID argument of a fetch function, failing with [`ScraperError::InvalidId`] if malformed
+pub(crate) fn to_author_id(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+) -> anyhow::Result<AuthorId> {
+    author_id.try_into().map_err(Into::into)
+}
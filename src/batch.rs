@@ -0,0 +1,130 @@
+use crate::{
+    fetch_author_info, rate_limit::stagger, AuthorInfo, FetchOptions, OutputFormat, ScraperError,
+};
+use anyhow::Result;
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+/// The outcome of fetching one author of a batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// Google Scholar ID the result belongs to
+    pub author_id: String,
+    /// The fetched profile or why fetching it failed
+    pub result: Result<AuthorInfo, ScraperError>,
+}
+
+/// Fetches several authors with a bounded number of requests in flight
+///
+/// Results are yielded in the order of `author_ids` as soon as they are
+/// available, so they can be written out while later ones are still loading.
+/// Each of the `concurrency` slots waits `options.politeness_delay` between
+/// its requests.
+///
+/// # Arguments
+///
+/// * `author_ids` - The Google Scholar IDs of the authors
+/// * `concurrency` - The maximum number of concurrent requests (at least 1)
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `impl Stream<Item = BatchResult>` - One result per author
+pub fn fetch_many<'a>(
+    author_ids: &'a [String],
+    concurrency: usize,
+    options: &'a FetchOptions,
+) -> impl Stream<Item = BatchResult> + 'a {
+    let concurrency = concurrency.max(1);
+
+    futures::stream::iter(author_ids.iter().enumerate())
+        .map(move |(i, author_id)| async move {
+            stagger(i, concurrency, options.politeness_delay).await;
+            BatchResult {
+                author_id: author_id.clone(),
+                result: fetch_author_info(author_id, options)
+                    .await
+                    .map_err(ScraperError::from),
+            }
+        })
+        .buffered(concurrency)
+}
+
+/// Writes each item of a stream as one line of JSON (NDJSON)
+///
+/// Every line is flushed as soon as its item arrives, which keeps memory flat
+/// for large batches and lets tools like `jq` process results incrementally.
+///
+/// # Arguments
+///
+/// * `writer` - The sink to write to
+/// * `results` - The items to serialize, e.g. the results of [`fetch_many`]
+pub async fn write_ndjson<W, S, T>(mut writer: W, results: S) -> Result<()>
+where
+    W: Write,
+    S: Stream<Item = T>,
+    T: Serialize,
+{
+    let mut results = std::pin::pin!(results);
+
+    while let Some(result) = results.next().await {
+        serde_json::to_writer(&mut writer, &result)?;
+        writer.write_all(b"\n")?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Writes each item of a stream as a YAML document of a multi-document stream
+///
+/// Every document starts with `---` and is flushed as soon as its item
+/// arrives, like [`write_ndjson`] does for JSON.
+///
+/// # Arguments
+///
+/// * `writer` - The sink to write to
+/// * `results` - The items to serialize, e.g. the results of [`fetch_many`]
+pub async fn write_batch_yaml<W, S, T>(mut writer: W, results: S) -> Result<()>
+where
+    W: Write,
+    S: Stream<Item = T>,
+    T: Serialize,
+{
+    let mut results = std::pin::pin!(results);
+
+    while let Some(result) = results.next().await {
+        writer.write_all(b"---\n")?;
+        writer.write_all(OutputFormat::Yaml.serialize(&result)?.as_bytes())?;
+        writer.flush()?;
+    }
+
+    Ok(())
+}
+
+/// Serializes several authors into one document
+///
+/// YAML gives a sequence and JSON an array of authors, each serialized like
+/// a single [`AuthorInfo`]. TOML can't have a list at the top level, so the
+/// authors are put into an `authors` array of tables there.
+///
+/// # Arguments
+///
+/// * `infos` - The authors, e.g. the successful results of [`fetch_many`]
+/// * `format` - The format of the document
+///
+/// # Returns
+///
+/// * `Result<String>` - The document, or [`ScraperError::Serialization`] describing why it failed
+pub fn serialize_batch(infos: &[AuthorInfo], format: OutputFormat) -> Result<String> {
+    #[derive(Serialize)]
+    struct Authors<'a> {
+        authors: &'a [AuthorInfo],
+    }
+
+    match format {
+        OutputFormat::Toml => format.serialize(&Authors { authors: infos }),
+        _ => format.serialize(&infos),
+    }
+}
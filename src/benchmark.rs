@@ -0,0 +1,49 @@
+use crate::AuthorInfo;
+use serde::{Deserialize, Serialize};
+
+/// Target metrics to compare an author against, e.g. the median of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Benchmark {
+    /// Target total number of citations
+    pub total: usize,
+    /// Target h-index
+    pub h_index: usize,
+    /// Target i10-index
+    pub i10_index: usize,
+}
+
+/// How an author measures up to a [`Benchmark`], in percent of each target
+///
+/// 100 means the target is met exactly. A metric is `None` if its target is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkResult {
+    /// Total citations in percent of the target
+    pub total: Option<f64>,
+    /// h-index in percent of the target
+    pub h_index: Option<f64>,
+    /// i10-index in percent of the target
+    pub i10_index: Option<f64>,
+}
+
+/// Compares an author's summary metrics to a benchmark
+///
+/// # Arguments
+///
+/// * `info` - The author
+/// * `target` - The metrics to compare against
+///
+/// # Returns
+///
+/// * `BenchmarkResult` - Each metric in percent of its target
+pub fn benchmark(info: &AuthorInfo, target: &Benchmark) -> BenchmarkResult {
+    BenchmarkResult {
+        total: percent_of(info.total, target.total),
+        h_index: percent_of(info.h_index, target.h_index),
+        i10_index: percent_of(info.i10_index, target.i10_index),
+    }
+}
+
+/// Returns `value` in percent of `target`, `None` for a zero target
+fn percent_of(value: usize, target: usize) -> Option<f64> {
+    (target > 0).then(|| value as f64 / target as f64 * 100.0)
+}
@@ -0,0 +1,103 @@
+use crate::{AuthorInfo, FetchMeta};
+use lru::LruCache;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A size-bounded in-memory cache of recent [`AuthorInfo`] results
+///
+/// Entries are keyed by author id and expire `ttl` after they were scraped.
+/// When the cache is full, the least recently used entry is evicted.
+///
+/// Clones share the same entries, so one cache can be handed to every request
+/// handler of a server. Set it as [`FetchOptions::cache`](crate::FetchOptions::cache)
+/// to have [`fetch_author_info`](crate::fetch_author_info) check it before
+/// fetching and fill it afterwards.
+///
+/// The entries are stored as fetched, so share a cache only between calls with
+/// the same options (e.g. the same `include_publications`).
+#[derive(Clone)]
+pub struct MemoryCache {
+    entries: Arc<Mutex<LruCache<String, (AuthorInfo, FetchMeta)>>>,
+    ttl: Duration,
+}
+
+impl MemoryCache {
+    /// Creates an empty cache
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The maximum number of authors kept (at least 1)
+    /// * `ttl` - How long an entry stays valid after it was scraped
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Arc::new(Mutex::new(LruCache::new(capacity))),
+            ttl,
+        }
+    }
+
+    /// Returns the cached result for an author, unless it is missing or expired
+    ///
+    /// The returned metadata has `from_cache` set.
+    pub fn get(&self, author_id: &str) -> Option<(AuthorInfo, FetchMeta)> {
+        let mut entries = self.lock();
+        let (_, meta) = entries.get(author_id)?;
+        if meta.age() >= self.ttl {
+            entries.pop(author_id);
+            return None;
+        }
+
+        entries.get(author_id).map(|(author_info, meta)| {
+            (
+                author_info.clone(),
+                FetchMeta {
+                    from_cache: true,
+                    ..*meta
+                },
+            )
+        })
+    }
+
+    /// Stores the result of a fetch
+    pub fn insert(&self, author_id: &str, author_info: AuthorInfo, meta: FetchMeta) {
+        self.lock().put(author_id.to_string(), (author_info, meta));
+    }
+
+    /// Removes the cached result for an author, e.g. to force a refresh
+    pub fn remove(&self, author_id: &str) {
+        self.lock().pop(author_id);
+    }
+
+    /// Removes all entries
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the number of entries, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether the cache has no entries
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Locks the entries, recovering them if another thread panicked while holding the lock
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruCache<String, (AuthorInfo, FetchMeta)>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("len", &self.len())
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{
+    extract_name, http_client, is_consent_page, is_robot_check_name, is_robot_check_page,
+    is_unavailable_page, is_valid_author_id, parse_html, profile_url, proxied_url, read_body,
+    retry::sleep, FetchOptions,
+};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+/// Whether a Google Scholar ID points to a public profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IdStatus {
+    /// The profile exists and is public
+    Valid,
+    /// There is no profile with this ID
+    NotFound,
+    /// The profile exists but isn't public
+    Private,
+    /// Scholar throttled the check, asked for cookie consent or couldn't be reached, so the ID is undecided
+    RateLimited,
+}
+
+/// Checks which IDs belong to public profiles, without extracting any data
+///
+/// Each ID costs one request without retries, waiting the default politeness
+/// delay between them. With [`check_ids_with_options`], the requests also
+/// wait for `options.rate_limiter`, if set.
+///
+/// # Arguments
+///
+/// * `ids` - The Google Scholar IDs to check
+///
+/// # Returns
+///
+/// * `Vec<(String, IdStatus)>` - The status of each ID, in the order of `ids`
+pub async fn check_ids(ids: &[String]) -> Vec<(String, IdStatus)> {
+    check_ids_with_options(ids, &FetchOptions::default()).await
+}
+
+/// Same as [`check_ids`], but with custom fetch options
+pub async fn check_ids_with_options(
+    ids: &[String],
+    options: &FetchOptions,
+) -> Vec<(String, IdStatus)> {
+    let mut statuses = Vec::with_capacity(ids.len());
+
+    for (i, id) in ids.iter().enumerate() {
+        if i > 0 {
+            sleep(options.politeness_delay).await;
+        }
+        statuses.push((id.clone(), check_id(id, options).await));
+    }
+
+    statuses
+}
+
+/// Classifies a single ID by the status and kind of its profile page
+async fn check_id(author_id: &str, options: &FetchOptions) -> IdStatus {
+    if !is_valid_author_id(author_id) {
+        return IdStatus::NotFound;
+    }
+    let Ok(client) = http_client(options) else {
+        return IdStatus::RateLimited;
+    };
+    if let Some(rate_limiter) = &options.rate_limiter {
+        rate_limiter.acquire().await;
+    }
+    let response = match client
+        .get(proxied_url(&profile_url(author_id), options))
+        .headers(options.headers.clone())
+        .send()
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            tracing::warn!("Checking {author_id} failed: {e}");
+            return IdStatus::RateLimited;
+        }
+    };
+
+    match response.status() {
+        StatusCode::OK => {}
+        StatusCode::NOT_FOUND => return IdStatus::NotFound,
+        StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED => return IdStatus::Private,
+        _ => return IdStatus::RateLimited,
+    }
+    let Ok(body) = read_body(response, options).await else {
+        return IdStatus::RateLimited;
+    };
+
+    let Ok(document) = parse_html(&body, options) else {
+        return IdStatus::RateLimited;
+    };
+    if is_unavailable_page(&document)
+        || is_consent_page(&document)
+        || is_robot_check_page(&document)
+    {
+        return IdStatus::RateLimited;
+    }
+    match extract_name(&document, options) {
+        Ok(name) if is_robot_check_name(&name) => IdStatus::RateLimited,
+        Ok(_) => IdStatus::Valid,
+        // Scholar answers with a page without profile for non-public profiles
+        Err(_) => IdStatus::Private,
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    fetch_author_info_with_meta, fetch_metrics, fetch_publications, http_client,
+    search_authors_page_with_options, AuthorId, AuthorInfo, FetchMeta, FetchOptions, Publication,
+    RateLimiter, SearchPage,
+};
+use anyhow::Result;
+#[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+use reqwest::cookie::Jar;
+use reqwest::header::{HeaderName, HeaderValue};
+#[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+use std::sync::Arc;
+use url::Url;
+
+/// A scraping session that keeps its identity across requests
+///
+/// With the `cookies` feature, cookies set by a response are stored and sent
+/// with all later requests of the same client (and its clones). Reusing the cookies of a browser session
+/// that already passed Scholar's consent dialog or a CAPTCHA makes further
+/// robot checks less likely.
+///
+/// To get these cookies, open <https://scholar.google.com> in a browser, then
+/// copy the cookies of `scholar.google.com` (and `google.com`, e.g. `SOCS` and
+/// `NID`) from the developer tools (Application / Storage → Cookies), or the
+/// `Cookie` request header of any Scholar request in the network tab, and pass
+/// them as `"NAME=value; OTHER=value"` to [`ScholarClient::with_cookies`].
+///
+/// Requests go through the CORS proxy, so the cookie store is keyed to the
+/// proxy URL and the cookies only take effect if the proxy forwards them.
+/// With your own proxy in [`FetchOptions::cors_proxy`] make sure it passes the
+/// `Cookie` and `Set-Cookie` headers through. An outbound proxy in
+/// [`FetchOptions::proxy`] sits in front of the CORS proxy and sees the same
+/// headers, cookies included.
+#[derive(Debug, Clone)]
+pub struct ScholarClient {
+    options: FetchOptions,
+}
+
+impl ScholarClient {
+    /// Creates a client (with an empty cookie store, if the `cookies` feature is enabled)
+    ///
+    /// A cookie store set in `options` is kept, e.g. one shared with another
+    /// client.
+    ///
+    /// Unless `options` bring their own [`RateLimiter`], one allowing a
+    /// request per [`FetchOptions::politeness_delay`] is set up and shared by
+    /// all clones of the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The fetch options used for all requests
+    pub fn new(options: FetchOptions) -> Self {
+        let options = with_rate_limiter(options);
+        Self {
+            #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+            options: FetchOptions {
+                cookie_store: options
+                    .cookie_store
+                    .clone()
+                    .or_else(|| Some(Arc::new(Jar::default()))),
+                ..options
+            },
+            #[cfg(not(all(feature = "cookies", not(target_arch = "wasm32"))))]
+            options,
+        }
+    }
+
+    /// Creates a client whose cookie store is seeded from a cookie string
+    ///
+    /// The cookies are added to the cookie store set in `options`, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The fetch options used for all requests
+    /// * `cookies` - The cookies as in a `Cookie` header, e.g. `"SOCS=...; NID=..."`
+    #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+    pub fn with_cookies(options: FetchOptions, cookies: &str) -> Self {
+        let options = with_rate_limiter(options);
+        let jar = options
+            .cookie_store
+            .clone()
+            .unwrap_or_else(|| Arc::new(Jar::default()));
+        // All requests are sent to the proxy, so that's where the cookies belong
+        let url = options.cors_proxy_url();
+        for cookie in cookies.split(';').map(str::trim).filter(|c| !c.is_empty()) {
+            jar.add_cookie_str(cookie, &url);
+        }
+
+        Self {
+            options: FetchOptions {
+                cookie_store: Some(jar),
+                ..options
+            },
+        }
+    }
+
+    /// Adds a header sent with every request of this client, see [`FetchOptions::headers`]
+    ///
+    /// A header of the same name that was added before is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name, e.g. `"Sec-Fetch-Mode"`
+    /// * `value` - The header value, e.g. `"navigate"`
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The client, or an error naming the invalid header name or value
+    pub fn with_header(mut self, name: &str, value: &str) -> Result<Self> {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| anyhow::anyhow!("Invalid header name {name:?}: {e}"))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|e| anyhow::anyhow!("Invalid value {value:?} of header {name}: {e}"))?;
+
+        self.options.headers.insert(header_name, header_value);
+        Ok(self)
+    }
+
+    /// Switches to a fresh exit of a Tor (or other SOCKS) proxy
+    ///
+    /// Tor isolates streams by their SOCKS credentials, so replacing the
+    /// username and password of [`FetchOptions::proxy`] (e.g.
+    /// `socks5h://127.0.0.1:9050`, needs the `socks` feature) with a random
+    /// token routes all further requests through a new circuit, usually with a
+    /// different exit IP. Call it between batches. Cookies are kept, so use a
+    /// new client instead if they should be dropped as well. A shared
+    /// [`FetchOptions::http_client`] (e.g. of a [`ScholarSession`](crate::ScholarSession))
+    /// is rebuilt with the new credentials, as it would keep using the old ones.
+    ///
+    /// Alternatively, with Tor's control port enabled, sending `SIGNAL NEWNYM`
+    /// (e.g. `printf 'AUTHENTICATE ""\r\nSIGNAL NEWNYM\r\n' | nc 127.0.0.1 9051`)
+    /// makes Tor use new circuits for all new connections.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<()>` - An error if no proxy is set, it isn't a valid URL, no random token could be drawn or the shared client can't be rebuilt
+    pub fn rotate_identity(&mut self) -> Result<()> {
+        let proxy = self
+            .options
+            .proxy
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No proxy configured to rotate"))?;
+        let mut url = Url::parse(proxy)?;
+
+        let mut token = [0u8; 16];
+        getrandom::getrandom(&mut token)
+            .map_err(|e| anyhow::anyhow!("No random token available: {e}"))?;
+        let token: String = token.iter().map(|byte| format!("{byte:02x}")).collect();
+
+        url.set_username("scholar")
+            .and_then(|()| url.set_password(Some(&token)))
+            .map_err(|()| anyhow::anyhow!("Proxy URL {proxy:?} can't have credentials"))?;
+        self.options.proxy = Some(url.to_string());
+
+        if self.options.http_client.take().is_some() {
+            self.options.http_client = Some(http_client(&self.options)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the fetch options of this client, including its cookie store
+    pub fn options(&self) -> &FetchOptions {
+        &self.options
+    }
+
+    /// Returns the fetch options of this client for changing them
+    pub(crate) fn options_mut(&mut self) -> &mut FetchOptions {
+        &mut self.options
+    }
+
+    /// Fetches an author's profile, see [`fetch_author_info`](crate::fetch_author_info)
+    pub async fn fetch_author_info(
+        &self,
+        author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    ) -> Result<AuthorInfo> {
+        let (author_info, _) = self.fetch_author_info_with_meta(author_id).await?;
+        Ok(author_info)
+    }
+
+    /// Same as [`ScholarClient::fetch_author_info`], but also returns metadata about the fetch
+    pub async fn fetch_author_info_with_meta(
+        &self,
+        author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    ) -> Result<(AuthorInfo, FetchMeta)> {
+        fetch_author_info_with_meta(author_id, &self.options).await
+    }
+
+    /// Fetches an author's summary metrics, see [`fetch_metrics`]
+    pub async fn fetch_metrics(
+        &self,
+        author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    ) -> Result<(usize, usize, usize)> {
+        fetch_metrics(author_id, &self.options).await
+    }
+
+    /// Fetches an author's publications, see [`fetch_publications`]
+    pub async fn fetch_publications(
+        &self,
+        author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    ) -> Result<Vec<Publication>> {
+        fetch_publications(author_id, &self.options).await
+    }
+
+    /// Fetches a page of the author search, see [`search_authors_page`](crate::search_authors_page)
+    pub async fn search_authors(&self, name: &str, cursor: Option<&str>) -> Result<SearchPage> {
+        search_authors_page_with_options(name, cursor, &self.options).await
+    }
+}
+
+/// Sets up a rate limiter of one request per politeness delay, unless one is configured
+fn with_rate_limiter(options: FetchOptions) -> FetchOptions {
+    let rate_limiter = options
+        .rate_limiter
+        .clone()
+        .unwrap_or_else(|| RateLimiter::new(options.politeness_delay));
+    FetchOptions {
+        rate_limiter: Some(rate_limiter),
+        ..options
+    }
+}
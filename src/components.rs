@@ -0,0 +1,297 @@
+use crate::{
+    dense_yearly_citations, fetch_author_info, profile_url, AuthorInfo, FetchOptions, Publication,
+    ScraperError,
+};
+use leptos::*;
+
+/// Height of the citation chart bars, in SVG units
+const CHART_HEIGHT: usize = 100;
+
+/// Width reserved for each year in the citation chart, in SVG units
+const CHART_BAR_WIDTH: usize = 24;
+
+/// Number of authors of a publication shown until "show all" is clicked
+const SHOWN_AUTHORS: usize = 3;
+
+/// Fetches and displays a Google Scholar profile
+///
+/// The component owns the request, so it can be dropped into any Leptos page:
+///
+/// ```ignore
+/// view! { <ScholarProfile author_id=EXAMPLE_AUTHOR_ID show_chart=false /> }
+/// ```
+#[component]
+pub fn ScholarProfile(
+    /// Google Scholar ID of the author
+    #[prop(into)]
+    author_id: MaybeSignal<String>,
+    /// Show the summary metrics (citations, h-index, i10-index)
+    #[prop(default = true)]
+    show_metrics: bool,
+    /// Show the chart of yearly citations
+    #[prop(default = true)]
+    show_chart: bool,
+) -> impl IntoView {
+    let info = create_resource(
+        move || author_id.get(),
+        |author_id| async move {
+            fetch_author_info(&author_id, &FetchOptions::default())
+                .await
+                .map(|info| (profile_url(&author_id), info))
+                .map_err(ScraperError::from)
+        },
+    );
+
+    view! {
+        <div class="scholar-profile">
+            <Suspense fallback=move || view! { <ProfileSkeleton show_metrics show_chart /> }>
+            {move || info.get().map(|result| match result {
+                Ok((url, info)) => view! {
+                    <h2><a href=url>{info.name.clone()}</a></h2>
+                    {show_metrics.then(|| view! { <Metrics info=info.clone() /> })}
+                    {show_chart.then(|| view! { <CitationChart yearly_citations=dense_yearly_citations(&info) /> })}
+                }.into_view(),
+                Err(e) => view! { <p class="error">{e.to_string()}</p> }.into_view(),
+            })}
+            </Suspense>
+        </div>
+    }
+}
+
+/// Placeholder in the shape of a [`ScholarProfile`], shown while it loads
+///
+/// Shimmering bars stand in for the name, the metrics table and the chart, so
+/// the layout doesn't jump when the data arrives.
+#[component]
+pub fn ProfileSkeleton(
+    /// Include a placeholder of the metrics table
+    #[prop(default = true)]
+    show_metrics: bool,
+    /// Include a placeholder of the citation chart
+    #[prop(default = true)]
+    show_chart: bool,
+) -> impl IntoView {
+    view! {
+        <div class="scholar-skeleton" aria-busy="true" aria-label="Loading">
+            <div class="skeleton skeleton-heading"></div>
+            {show_metrics.then(|| view! {
+                <table class="scholar-metrics">
+                    {(0..3).map(|_| view! {
+                        <tr>
+                            <td><div class="skeleton skeleton-label"></div></td>
+                            <td><div class="skeleton skeleton-value"></div></td>
+                        </tr>
+                    }).collect_view()}
+                </table>
+            })}
+            {show_chart.then(|| view! { <div class="skeleton skeleton-chart"></div> })}
+        </div>
+    }
+}
+
+/// Sortable table of publications, e.g. of [`AuthorInfo::publications`]
+///
+/// Clicking a column header sorts by that column, clicking it again reverses
+/// the order. Sorting happens in the browser, without new requests. Long
+/// author lists are cut after a few names with a toggle to show all.
+///
+/// ```ignore
+/// view! { <PublicationList publications=info.publications.clone() /> }
+/// ```
+#[component]
+pub fn PublicationList(
+    /// The publications to list, initially in the given order
+    #[prop(into)]
+    publications: MaybeSignal<Vec<Publication>>,
+) -> impl IntoView {
+    let (sort, set_sort) = create_signal(None::<(SortColumn, bool)>);
+
+    let sorted = move || {
+        let mut publications = publications.get();
+        if let Some((column, descending)) = sort.get() {
+            publications.sort_by(|a, b| {
+                let order = column.compare(a, b);
+                if descending {
+                    order.reverse()
+                } else {
+                    order
+                }
+            });
+        }
+        publications
+    };
+    let header = move |column: SortColumn| {
+        let indicator = move || match sort.get() {
+            Some((sorted_by, descending)) if sorted_by == column => {
+                if descending {
+                    " ▼"
+                } else {
+                    " ▲"
+                }
+            }
+            _ => "",
+        };
+        view! {
+            <th>
+                <button class="sort" on:click=move |_| set_sort.update(|sort| {
+                    *sort = match *sort {
+                        Some((sorted_by, descending)) if sorted_by == column => Some((column, !descending)),
+                        _ => Some((column, column.descending_first())),
+                    }
+                })>
+                    {column.label()}{indicator}
+                </button>
+            </th>
+        }
+    };
+
+    view! {
+        <table class="publication-list">
+            <thead>
+                <tr>
+                    {header(SortColumn::Title)}
+                    {header(SortColumn::Citations)}
+                    {header(SortColumn::Year)}
+                </tr>
+            </thead>
+            <tbody>
+                {move || sorted().into_iter().map(|publication| view! {
+                    <PublicationRow publication />
+                }).collect_view()}
+            </tbody>
+        </table>
+    }
+}
+
+/// Column a [`PublicationList`] is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Title,
+    Year,
+    Citations,
+}
+
+impl SortColumn {
+    /// Header text of the column
+    fn label(self) -> &'static str {
+        match self {
+            SortColumn::Title => "Title",
+            SortColumn::Year => "Year",
+            SortColumn::Citations => "Cited by",
+        }
+    }
+
+    /// Whether the first click sorts descending, i.e. newest or most cited first
+    fn descending_first(self) -> bool {
+        self != SortColumn::Title
+    }
+
+    /// Compares two publications by this column in ascending order
+    fn compare(self, a: &Publication, b: &Publication) -> std::cmp::Ordering {
+        match self {
+            SortColumn::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+            SortColumn::Year => a.year.cmp(&b.year),
+            SortColumn::Citations => a.citations.cmp(&b.citations),
+        }
+    }
+}
+
+/// Row of a [`PublicationList`], with its own "show all" toggle for the authors
+#[component]
+fn PublicationRow(publication: Publication) -> impl IntoView {
+    let (show_all, set_show_all) = create_signal(false);
+    let authors = publication.authors.clone();
+    let truncated = authors.len() > SHOWN_AUTHORS;
+
+    let title = match &publication.url {
+        Some(url) => view! { <a href=url.to_string()>{publication.title.clone()}</a> }.into_view(),
+        None => publication.title.clone().into_view(),
+    };
+    let author_list = move || {
+        if show_all.get() || !truncated {
+            authors.join(", ")
+        } else {
+            format!("{}, …", authors[..SHOWN_AUTHORS].join(", "))
+        }
+    };
+
+    view! {
+        <tr>
+            <td>
+                <div class="publication-title">{title}</div>
+                <div class="publication-authors">
+                    {author_list}
+                    {truncated.then(|| view! {
+                        " "
+                        <button class="show-all" on:click=move |_| set_show_all.update(|show| *show = !*show)>
+                            {move || if show_all.get() { "show less" } else { "show all" }}
+                        </button>
+                    })}
+                </div>
+                {publication.venue.clone().map(|venue| view! { <div class="publication-venue">{venue}</div> })}
+            </td>
+            <td>
+                <span class="citation-badge">{publication.citations}</span>
+            </td>
+            <td>{publication.year}</td>
+        </tr>
+    }
+}
+
+/// Table of the summary metrics
+#[component]
+fn Metrics(info: AuthorInfo) -> impl IntoView {
+    view! {
+        <table class="scholar-metrics">
+            <tr><td>"Citations"</td><td>{info.total}</td></tr>
+            <tr><td>"h-index"</td><td>{info.h_index}</td></tr>
+            <tr><td>"i10-index"</td><td>{info.i10_index}</td></tr>
+        </table>
+    }
+}
+
+/// Bar chart of the yearly citation counts, with a bar for every year of the range
+#[component]
+fn CitationChart(yearly_citations: Vec<(usize, usize)>) -> impl IntoView {
+    let max = yearly_citations
+        .iter()
+        .map(|&(_, count)| count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let width = yearly_citations.len() * CHART_BAR_WIDTH;
+
+    let bars = yearly_citations
+        .into_iter()
+        .enumerate()
+        .map(|(i, (year, count))| {
+            let x = i * CHART_BAR_WIDTH;
+            let height = count * CHART_HEIGHT / max;
+            view! {
+                <rect
+                    x=x + 2
+                    y=CHART_HEIGHT - height
+                    width=CHART_BAR_WIDTH - 4
+                    height=height
+                    fill="#4a6d94"
+                >
+                    <title>{format!("{year}: {count}")}</title>
+                </rect>
+                <text
+                    x=x + CHART_BAR_WIDTH / 2
+                    y=CHART_HEIGHT + 12
+                    font-size="8"
+                    text-anchor="middle"
+                >
+                    {year % 100}
+                </text>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <svg class="citation-chart" viewBox=format!("0 0 {width} {}", CHART_HEIGHT + 16)>
+            {bars}
+        </svg>
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{with_retry, RetryPolicy};
+use anyhow::Result;
+use serde::Deserialize;
+use url::form_urlencoded;
+
+/// Works endpoint of the Crossref REST API
+const CROSSREF_URL: &str = "https://api.crossref.org/works";
+
+/// Number of candidates requested from Crossref
+const CROSSREF_ROWS: usize = 5;
+
+/// Options of the Crossref lookup
+#[derive(Debug, Clone)]
+pub struct CrossrefOptions {
+    /// Contact address sent as `mailto`, which routes requests to Crossref's faster "polite" pool
+    pub mailto: Option<String>,
+    /// Minimum relevance score of a match, lower scores count as no match
+    pub min_score: f64,
+    /// Retry behaviour when Crossref throttles requests (status 429 or 503)
+    pub retry: RetryPolicy,
+}
+
+impl Default for CrossrefOptions {
+    fn default() -> Self {
+        Self {
+            mailto: None,
+            min_score: 60.0,
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+/// The part of Crossref's response needed to pick a DOI
+#[derive(Deserialize)]
+struct CrossrefResponse {
+    message: CrossrefMessage,
+}
+
+#[derive(Deserialize)]
+struct CrossrefMessage {
+    items: Vec<CrossrefItem>,
+}
+
+#[derive(Deserialize)]
+struct CrossrefItem {
+    #[serde(rename = "DOI")]
+    doi: String,
+    score: f64,
+}
+
+/// Looks up the DOI of a publication by its title on Crossref
+///
+/// # Arguments
+///
+/// * `title` - The title of the publication
+/// * `year` - The year of publication, narrows the search if known
+///
+/// # Returns
+///
+/// * `Result<Option<String>>` - The DOI of the best match, `None` if no work scored high enough
+pub async fn resolve_doi_crossref(title: &str, year: Option<usize>) -> Result<Option<String>> {
+    resolve_doi_crossref_with_options(title, year, &CrossrefOptions::default()).await
+}
+
+/// Same as [`resolve_doi_crossref`], but with custom options
+pub async fn resolve_doi_crossref_with_options(
+    title: &str,
+    year: Option<usize>,
+    options: &CrossrefOptions,
+) -> Result<Option<String>> {
+    let mut query = form_urlencoded::Serializer::new(String::new());
+    query
+        .append_pair("query.bibliographic", title)
+        .append_pair("rows", &CROSSREF_ROWS.to_string())
+        .append_pair("select", "DOI,score");
+    if let Some(year) = year {
+        query.append_pair(
+            "filter",
+            &format!("from-pub-date:{year},until-pub-date:{year}"),
+        );
+    }
+    if let Some(mailto) = &options.mailto {
+        query.append_pair("mailto", mailto);
+    }
+
+    // Crossref allows cross-origin requests, so no proxy is needed
+    let url = format!("{CROSSREF_URL}?{}", query.finish());
+    let response: CrossrefResponse = with_retry(&options.retry, |_| async {
+        Ok(reqwest::get(&url).await?.error_for_status()?.json().await?)
+    })
+    .await?;
+
+    Ok(response
+        .message
+        .items
+        .into_iter()
+        .filter(|item| item.score >= options.min_score)
+        .max_by(|a, b| a.score.total_cmp(&b.score))
+        .map(|item| item.doi))
+}
@@ -0,0 +1,290 @@
+use crate::{
+    element_text, fetch_html, rate_limit::stagger, resolve_url, selectors, to_author_id, AuthorId,
+    FetchOptions, ScraperError, SCHOLAR_LANGUAGE, SCHOLAR_URL,
+};
+use anyhow::Result;
+use futures::StreamExt;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use url::{form_urlencoded, Url};
+
+/// The detail page of a publication
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicationDetail {
+    /// Title of the publication
+    pub title: String,
+    /// Link to the publication at its publisher, if the title links there
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Full list of authors (the publication list shortens it)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    /// Publication date, as precise as the page gives it
+    ///
+    /// The text as shown is kept in `fields` under "Publication date".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publication_date: Option<PublicationDate>,
+    /// Journal, conference, book or other source
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    /// Abstract or description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// All fields of the page (e.g. "Volume", "Pages", "Publisher") as label and text
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+/// A possibly partial date, e.g. only the year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PublicationDate {
+    /// Year
+    pub year: usize,
+    /// Month from 1 to 12
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub month: Option<u8>,
+    /// Day of the month from 1 to 31, only set together with the month
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub day: Option<u8>,
+}
+
+/// Month names of the page language (English, as pages are requested with `hl=en`)
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+impl PublicationDate {
+    /// Parses a date as shown on Scholar's detail pages
+    ///
+    /// Understands numeric dates ordered year/month/day with `/`, `-` or `.`
+    /// (`2020/3/15`, `2020-03`, `2020`) and dates with month names in any
+    /// order (`March 2020`, `15 Mar 2020`, `Mar 15, 2020`). A month or day
+    /// that can't exist is dropped rather than failing the whole date, so
+    /// `2020/13` is the year 2020: the year alone still sorts and filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The date text
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Self>` - The date, `None` if no valid year was found
+    pub fn parse(text: &str) -> Option<Self> {
+        let parts: Vec<&str> = text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        let date = match parts.iter().position(|part| month_number(part).is_some()) {
+            // The year is the four-digit number, the day the other one
+            Some(i) => {
+                let numbers = || parts.iter().filter_map(|part| part.parse::<usize>().ok());
+                Self {
+                    year: numbers().find(|number| *number >= 1000)?,
+                    month: month_number(parts[i]),
+                    day: numbers()
+                        .find(|number| *number < 1000)
+                        .and_then(|day| u8::try_from(day).ok()),
+                }
+            }
+            None => {
+                let mut numbers = parts.iter().map(|part| part.parse::<usize>());
+                Self {
+                    year: numbers.next()?.ok()?,
+                    month: numbers
+                        .next()
+                        .and_then(|month| u8::try_from(month.ok()?).ok()),
+                    day: numbers.next().and_then(|day| u8::try_from(day.ok()?).ok()),
+                }
+            }
+        };
+
+        date.validated()
+    }
+
+    /// Drops a month or day that can't exist, and fails on an implausible year
+    fn validated(mut self) -> Option<Self> {
+        if !(1000..=9999).contains(&self.year) {
+            return None;
+        }
+        if let Some(month) = self.month.filter(|month| !(1..=12).contains(month)) {
+            tracing::debug!(month, "dropping impossible month of {}", self.year);
+            self.month = None;
+        }
+        if let Some(day) = self.day.filter(|day| !(1..=31).contains(day)) {
+            tracing::debug!(day, "dropping impossible day of {}", self.year);
+            self.day = None;
+        }
+        if self.month.is_none() {
+            self.day = None;
+        }
+        Some(self)
+    }
+}
+
+impl fmt::Display for PublicationDate {
+    /// Formats the date as ISO 8601, as far as it is known (`2020-03-15`, `2020-03` or `2020`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{month:02}")?;
+            if let Some(day) = self.day {
+                write!(f, "-{day:02}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of a month given by name or abbreviation of at least three letters
+fn month_number(name: &str) -> Option<u8> {
+    let name = name.to_lowercase();
+    if name.len() < 3 || name.chars().any(|c| !c.is_alphabetic()) {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(&name))
+        .map(|i| i as u8 + 1)
+}
+
+/// Labels of the fields holding the venue, depending on the kind of publication
+const VENUE_FIELDS: [&str; 5] = ["Journal", "Conference", "Book", "Source", "Publisher"];
+
+/// Builds the URL of a publication's detail page
+fn detail_url(author_id: &AuthorId, cite_id: &str) -> String {
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("view_op", "view_citation")
+        .append_pair("hl", SCHOLAR_LANGUAGE)
+        .append_pair("user", author_id)
+        .append_pair("citation_for_view", cite_id)
+        .finish();
+    format!("{SCHOLAR_URL}/citations?{query}")
+}
+
+/// Fetches the detail page of a publication
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `cite_id` - The publication's [`cite_id`](crate::Publication::cite_id)
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `Result<PublicationDetail>` - The details of the publication
+pub async fn fetch_publication_detail(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    cite_id: &str,
+    options: &FetchOptions,
+) -> Result<PublicationDetail> {
+    let url = detail_url(&to_author_id(author_id)?, cite_id);
+    extract_publication_detail(&fetch_html(&url, options).await?)
+}
+
+/// Fetches the detail pages of several publications with a bounded number of requests in flight
+///
+/// Each of the `concurrency` slots waits `options.politeness_delay` between
+/// its requests. A failing page only fails its own entry, a malformed
+/// `author_id` fails all of them.
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `cite_ids` - The [`cite_id`](crate::Publication::cite_id)s of the publications
+/// * `concurrency` - The maximum number of concurrent requests (at least 1)
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `BTreeMap<String, Result<PublicationDetail, ScraperError>>` - The details or why fetching them failed, by cite ID
+pub async fn fetch_publication_details(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    cite_ids: &[String],
+    concurrency: usize,
+    options: &FetchOptions,
+) -> BTreeMap<String, Result<PublicationDetail, ScraperError>> {
+    let concurrency = concurrency.max(1);
+    let author_id = match to_author_id(author_id) {
+        Ok(author_id) => author_id,
+        Err(error) => {
+            let error = ScraperError::from(error);
+            return cite_ids
+                .iter()
+                .map(|cite_id| (cite_id.clone(), Err(error.clone())))
+                .collect();
+        }
+    };
+    let author_id = &author_id;
+
+    futures::stream::iter(cite_ids.iter().enumerate())
+        .map(|(i, cite_id)| async move {
+            stagger(i, concurrency, options.politeness_delay).await;
+            let detail = fetch_publication_detail(author_id, cite_id, options)
+                .await
+                .map_err(ScraperError::from);
+            (cite_id.clone(), detail)
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await
+}
+
+/// Extracts the details of a publication from its detail page
+fn extract_publication_detail(document: &Html) -> Result<PublicationDetail> {
+    let title_selector = Selector::parse(selectors::PUBLICATION_DETAIL_TITLE).unwrap();
+    let link_selector = Selector::parse("a").unwrap();
+    let row_selector = Selector::parse(selectors::PUBLICATION_DETAIL_FIELDS).unwrap();
+    let label_selector = Selector::parse(selectors::PUBLICATION_DETAIL_LABEL).unwrap();
+    let value_selector = Selector::parse(selectors::PUBLICATION_DETAIL_VALUE).unwrap();
+
+    let title = document
+        .select(&title_selector)
+        .next()
+        .ok_or_else(|| ScraperError::ParseError("no publication title".to_string()))?;
+    let url = title
+        .select(&link_selector)
+        .next()
+        .and_then(|link| link.value().attr("href"))
+        .and_then(resolve_url);
+
+    let fields: BTreeMap<String, String> = document
+        .select(&row_selector)
+        .filter_map(|row| {
+            let label = element_text(row.select(&label_selector).next()?);
+            let value = element_text(row.select(&value_selector).next()?);
+            (!label.is_empty() && !value.is_empty()).then_some((label, value))
+        })
+        .collect();
+
+    Ok(PublicationDetail {
+        title: element_text(title),
+        url,
+        authors: fields
+            .get("Authors")
+            .map(|authors| authors.split(", ").map(String::from).collect())
+            .unwrap_or_default(),
+        publication_date: fields
+            .get("Publication date")
+            .and_then(|date| PublicationDate::parse(date)),
+        venue: VENUE_FIELDS
+            .iter()
+            .find_map(|label| fields.get(*label))
+            .cloned(),
+        description: fields.get("Description").cloned(),
+        fields,
+    })
+}
@@ -0,0 +1,91 @@
+use crate::AuthorInfo;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Changes between two snapshots of the same profile
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorDiff {
+    /// Total citations as (old, new), if changed
+    pub total: Option<(usize, usize)>,
+    /// h-index as (old, new), if changed
+    pub h_index: Option<(usize, usize)>,
+    /// i10-index as (old, new), if changed
+    pub i10_index: Option<(usize, usize)>,
+    /// Citations of the years that changed as (old, new), missing years count as 0
+    pub yearly_citations: BTreeMap<usize, (usize, usize)>,
+    /// Titles of publications that are new in the newer snapshot
+    pub new_publications: Vec<String>,
+}
+
+impl AuthorDiff {
+    /// Checks whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl AuthorInfo {
+    /// Compares this snapshot with a newer one of the same profile
+    pub fn diff(&self, newer: &AuthorInfo) -> AuthorDiff {
+        let changed = |old: usize, new: usize| (old != new).then_some((old, new));
+
+        let years: BTreeSet<_> = self
+            .yearly_citations
+            .keys()
+            .chain(newer.yearly_citations.keys())
+            .copied()
+            .collect();
+        let yearly_citations = years
+            .into_iter()
+            .filter_map(|year| {
+                let old = self.yearly_citations.get(&year).copied().unwrap_or(0);
+                let new = newer.yearly_citations.get(&year).copied().unwrap_or(0);
+                Some((year, changed(old, new)?))
+            })
+            .collect();
+
+        let known: BTreeSet<_> = self.publications.iter().map(|p| &p.title).collect();
+        let new_publications = newer
+            .publications
+            .iter()
+            .filter(|p| !known.contains(&p.title))
+            .map(|p| p.title.clone())
+            .collect();
+
+        AuthorDiff {
+            total: changed(self.total, newer.total),
+            h_index: changed(self.h_index, newer.h_index),
+            i10_index: changed(self.i10_index, newer.i10_index),
+            yearly_citations,
+            new_publications,
+        }
+    }
+}
+
+impl fmt::Display for AuthorDiff {
+    /// Writes one line per change, e.g. "citations: 1200 -> 1234 (+34)"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line = |f: &mut fmt::Formatter<'_>, label: &str, (old, new): (usize, usize)| {
+            let delta = new as i64 - old as i64;
+            writeln!(f, "{label}: {old} -> {new} ({delta:+})")
+        };
+
+        if let Some(change) = self.total {
+            line(f, "citations", change)?;
+        }
+        if let Some(change) = self.h_index {
+            line(f, "h-index", change)?;
+        }
+        if let Some(change) = self.i10_index {
+            line(f, "i10-index", change)?;
+        }
+        for (year, change) in &self.yearly_citations {
+            line(f, &format!("citations in {year}"), *change)?;
+        }
+        for title in &self.new_publications {
+            writeln!(f, "new publication: {title}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use crate::ScraperError;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Text format for serializing results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OutputFormat {
+    /// YAML, as returned by [`fetch_info`](crate::fetch_info)
+    #[default]
+    Yaml,
+    /// Compact JSON on a single line
+    Json,
+    /// Indented JSON
+    JsonPretty,
+    /// TOML, with map keys as strings and missing values left out
+    Toml,
+}
+
+impl OutputFormat {
+    /// All formats, e.g. to offer them for selection
+    pub const ALL: [OutputFormat; 4] = [
+        OutputFormat::Yaml,
+        OutputFormat::Json,
+        OutputFormat::JsonPretty,
+        OutputFormat::Toml,
+    ];
+
+    /// Serializes a value in this format
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to serialize, e.g. an [`AuthorInfo`](crate::AuthorInfo)
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String>` - The serialized text, or [`ScraperError::Serialization`] describing why it failed
+    pub fn serialize<T: Serialize>(self, value: &T) -> Result<String> {
+        self.to_text(value)
+            .map_err(|e| ScraperError::Serialization(format!("{self}: {e}")).into())
+    }
+
+    /// Serializes a value in this format, with the serializer's own error
+    fn to_text<T: Serialize>(self, value: &T) -> Result<String> {
+        Ok(match self {
+            OutputFormat::Yaml => serde_yaml::to_string(value)?,
+            OutputFormat::Json => serde_json::to_string(value)?,
+            OutputFormat::JsonPretty => serde_json::to_string_pretty(value)?,
+            OutputFormat::Toml => toml::to_string(&toml_compatible(serde_json::to_value(value)?))?,
+        })
+    }
+
+    /// Returns the usual file extension of the format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Json | OutputFormat::JsonPretty => "json",
+            OutputFormat::Toml => "toml",
+        }
+    }
+
+    /// Returns the MIME type of the format
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            OutputFormat::Yaml => "application/yaml",
+            OutputFormat::Json | OutputFormat::JsonPretty => "application/json",
+            OutputFormat::Toml => "application/toml",
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            OutputFormat::Yaml => "yaml",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonPretty => "json-pretty",
+            OutputFormat::Toml => "toml",
+        })
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    /// Parses the names printed by the [`Display`](fmt::Display) impl
+    fn from_str(s: &str) -> Result<Self> {
+        OutputFormat::ALL
+            .into_iter()
+            .find(|format| format.to_string() == s)
+            .ok_or_else(|| anyhow::anyhow!("Unknown output format {s:?}"))
+    }
+}
+
+/// Adapts a value to what TOML can represent
+///
+/// Going through JSON turns integer map keys (like the years) into strings.
+/// TOML has no null, so missing values are removed.
+fn toml_compatible(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, toml_compatible(value)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .filter(|value| !value.is_null())
+                .map(toml_compatible)
+                .collect(),
+        ),
+        value => value,
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    fetch_author_info, rate_limit::stagger, to_author_id, AuthorId, FetchOptions, Progress,
+};
+use anyhow::Result;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A collaboration network built from the co-author lists of profiles
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoauthorGraph {
+    /// Authors in the graph, keyed by Google Scholar ID
+    pub nodes: BTreeMap<String, GraphNode>,
+    /// Collaborations as pairs of author IDs, the smaller ID first
+    pub edges: BTreeSet<(String, String)>,
+    /// Whether expansion stopped because the node limit was reached
+    pub truncated: bool,
+}
+
+/// An author in a [`CoauthorGraph`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphNode {
+    /// Author name
+    pub name: String,
+    /// h-index, `None` if the profile was not fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub h_index: Option<usize>,
+}
+
+/// Builds the co-author graph around an author by breadth-first traversal
+///
+/// Every node is fetched at most once, so at most `max_nodes` requests are
+/// made. Once the graph holds `max_nodes` authors no new ones are added and
+/// `truncated` is set. Up to `concurrency` requests are in flight, and each
+/// slot waits `options.politeness_delay` between its requests. Co-authors whose profile
+/// fails to load stay in the graph without being expanded.
+///
+/// With [`FetchOptions::include_publications`], every fetched profile also
+/// loads its publications (up to [`FetchOptions::max_publications`]), which
+/// multiplies the number of requests.
+///
+/// Progress is reported after every profile. The total estimate is the
+/// number of authors discovered so far, so it grows while the graph expands.
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author at the center
+/// * `max_nodes` - The maximum number of authors in the graph
+/// * `concurrency` - The maximum number of concurrent requests (at least 1)
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `Result<CoauthorGraph>` - The graph, or an error if the central profile fails to load
+pub async fn build_coauthor_graph(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    max_nodes: usize,
+    concurrency: usize,
+    options: &FetchOptions,
+) -> Result<CoauthorGraph> {
+    let mut graph = CoauthorGraph::default();
+    let author_id = to_author_id(author_id)?.to_string();
+    let mut level = vec![author_id.clone()];
+    let mut requested = 0;
+    let fetched = AtomicUsize::new(0);
+
+    while !level.is_empty() {
+        let results: Vec<_> = futures::stream::iter(level.iter().enumerate())
+            .map(|(i, id)| {
+                // Numbered across levels, so a level doesn't start with a burst
+                let index = requested + i;
+                let (fetched, discovered) = (&fetched, graph.nodes.len().max(1));
+                async move {
+                    stagger(index, concurrency.max(1), options.politeness_delay).await;
+                    let result = fetch_author_info(id, options).await;
+                    options.report(Progress {
+                        fetched: fetched.fetch_add(1, Ordering::Relaxed) + 1,
+                        total_estimate: Some(discovered),
+                        current_author: id.clone(),
+                    });
+                    result
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+        requested += level.len();
+
+        let mut next_level = Vec::new();
+        for (id, result) in level.iter().zip(results) {
+            let info = match result {
+                Ok(info) => info,
+                Err(e) if *id == author_id => return Err(e),
+                Err(e) => {
+                    tracing::warn!("Not expanding co-author {id}: {e}");
+                    continue;
+                }
+            };
+
+            graph.nodes.insert(
+                id.clone(),
+                GraphNode {
+                    name: info.name,
+                    h_index: Some(info.h_index),
+                },
+            );
+
+            for coauthor in info.coauthors {
+                if !graph.nodes.contains_key(&coauthor.author_id) {
+                    if graph.nodes.len() >= max_nodes {
+                        graph.truncated = true;
+                        continue;
+                    }
+                    graph.nodes.insert(
+                        coauthor.author_id.clone(),
+                        GraphNode {
+                            name: coauthor.name,
+                            h_index: None,
+                        },
+                    );
+                    next_level.push(coauthor.author_id.clone());
+                }
+
+                let edge = if *id < coauthor.author_id {
+                    (id.clone(), coauthor.author_id)
+                } else {
+                    (coauthor.author_id, id.clone())
+                };
+                graph.edges.insert(edge);
+            }
+        }
+
+        level = next_level;
+    }
+
+    Ok(graph)
+}
+
+/// Exports a co-author graph in Graphviz DOT, e.g. to render it with `dot -Tsvg`
+///
+/// Nodes are identified by their Scholar ID and labeled with the author's
+/// name. Nodes with a known h-index get a width growing with its square root,
+/// so prolific authors stand out, the others keep Graphviz's default size.
+///
+/// # Arguments
+///
+/// * `graph` - The graph to export
+///
+/// # Returns
+///
+/// * `String` - The undirected graph in DOT syntax
+pub fn coauthor_graph_to_dot(graph: &CoauthorGraph) -> String {
+    let mut dot = String::from("graph coauthors {\n    node [shape=ellipse];\n");
+
+    for (id, node) in &graph.nodes {
+        let mut attributes = format!("label=\"{}\"", dot_escape(&node.name));
+        if let Some(h_index) = node.h_index {
+            let width = 0.75 + (h_index as f64).sqrt() * 0.25;
+            attributes.push_str(&format!(", width={width:.2}"));
+        }
+        dot.push_str(&format!("    \"{}\" [{attributes}];\n", dot_escape(id)));
+    }
+    for (a, b) in &graph.edges {
+        dot.push_str(&format!(
+            "    \"{}\" -- \"{}\";\n",
+            dot_escape(a),
+            dot_escape(b)
+        ));
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Escapes a string for a double-quoted DOT identifier
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace(['\n', '\r'], " ")
+}
@@ -0,0 +1,113 @@
+use crate::{
+    extract_name, http_client, is_consent_page, is_robot_check_name, is_robot_check_page,
+    is_unavailable_page, parse_html, profile_url, proxied_url, read_body, retry::unix_now,
+    FetchOptions, EXAMPLE_AUTHOR_ID,
+};
+use anyhow::Result;
+use reqwest::StatusCode;
+use scraper::Html;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Whether Google Scholar can currently be scraped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HealthState {
+    /// A profile page was served and parsed
+    Reachable,
+    /// Scholar throttled the request or is over its quota
+    RateLimited,
+    /// Google asked for cookie consent, see [`CONSENT_COOKIE`](crate::CONSENT_COOKIE)
+    ConsentRequired,
+    /// Scholar served a CAPTCHA or another robot check
+    RobotCheck,
+    /// The proxy or Scholar didn't answer, or answered with an unexpected status
+    Unreachable,
+}
+
+/// The outcome of a [`health_check`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthStatus {
+    /// What the check found
+    pub state: HealthState,
+    /// Time from sending the request until the page was classified
+    pub latency: Duration,
+}
+
+impl HealthStatus {
+    /// Whether profiles can be fetched right now
+    pub fn is_healthy(&self) -> bool {
+        self.state == HealthState::Reachable
+    }
+}
+
+/// Checks whether Google Scholar is reachable and serves profile pages
+///
+/// This fetches the profile of [`EXAMPLE_AUTHOR_ID`] once, without retries,
+/// e.g. to back the `/health` endpoint of a service.
+///
+/// # Returns
+///
+/// * `Result<HealthStatus>` - What the check found and how long it took, an error only if no HTTP client could be built
+pub async fn health_check() -> Result<HealthStatus> {
+    health_check_with_options(&FetchOptions::default()).await
+}
+
+/// Same as [`health_check`], but with custom fetch options (e.g. a proxy or cookies)
+///
+/// The request waits for `options.rate_limiter`, if set, so a `/health`
+/// endpoint doesn't add to a busy scraper's load. The wait isn't part of the
+/// reported latency.
+pub async fn health_check_with_options(options: &FetchOptions) -> Result<HealthStatus> {
+    let client = http_client(options)?;
+    if let Some(rate_limiter) = &options.rate_limiter {
+        rate_limiter.acquire().await;
+    }
+    let started = unix_now();
+
+    let state = match client
+        .get(proxied_url(&profile_url(EXAMPLE_AUTHOR_ID), options))
+        .headers(options.headers.clone())
+        .send()
+        .await
+    {
+        Ok(response) => match response.status() {
+            StatusCode::OK => match read_body(response, options).await {
+                Ok(body) => match parse_html(&body, options) {
+                    Ok(document) => classify_page(&document, options),
+                    Err(_) => HealthState::Unreachable,
+                },
+                Err(_) => HealthState::Unreachable,
+            },
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {
+                HealthState::RateLimited
+            }
+            _ => HealthState::Unreachable,
+        },
+        Err(e) => {
+            tracing::warn!("Health check failed: {e}");
+            HealthState::Unreachable
+        }
+    };
+
+    Ok(HealthStatus {
+        state,
+        latency: unix_now().saturating_sub(started),
+    })
+}
+
+/// Classifies a page that was served with status 200
+fn classify_page(document: &Html, options: &FetchOptions) -> HealthState {
+    if is_consent_page(document) {
+        return HealthState::ConsentRequired;
+    }
+    if is_unavailable_page(document) {
+        return HealthState::RateLimited;
+    }
+    if is_robot_check_page(document) {
+        return HealthState::RobotCheck;
+    }
+    match extract_name(document, options) {
+        Ok(name) if !is_robot_check_name(&name) => HealthState::Reachable,
+        _ => HealthState::RobotCheck,
+    }
+}
@@ -1,31 +1,544 @@
+//! Scrapes author profiles from Google Scholar
+//!
+//! # Tracing
+//!
+//! Every request is a `GET` span with the OpenTelemetry HTTP client attributes
+//! (`http.method`, `http.url`, `http.status_code`, `http.resend_count`,
+//! `otel.kind`, `otel.status_code`), nested in a `fetch_author_info` span
+//! (`scholar.author_id`) next to a `parse_author_info` span. To export them,
+//! add a [`tracing-opentelemetry`](https://docs.rs/tracing-opentelemetry)
+//! layer to the subscriber:
+//!
+//! ```ignore
+//! use tracing_subscriber::prelude::*;
+//!
+//! let tracer = opentelemetry_otlp::new_pipeline()
+//!     .tracing()
+//!     .with_exporter(opentelemetry_otlp::new_exporter().tonic())
+//!     .install_batch(opentelemetry_sdk::runtime::Tokio)?;
+//! tracing_subscriber::registry()
+//!     .with(tracing_opentelemetry::layer().with_tracer(tracer))
+//!     .init();
+//! ```
+
 use anyhow::Result;
+use futures::StreamExt;
+use regex::Regex;
+use reqwest::header::HeaderMap;
 use reqwest::StatusCode;
-use scraper::{Html, Selector};
+use scraper::{ElementRef, Html, Selector};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
 use thiserror::Error;
+use tracing::Instrument;
+use url::{form_urlencoded, Url};
+
+mod anonymize;
+mod author_id;
+mod batch;
+mod benchmark;
+mod cache;
+mod check;
+mod client;
+#[cfg(feature = "app")]
+mod components;
+#[cfg(feature = "crossref")]
+mod crossref;
+mod details;
+mod diff;
+mod format;
+mod graph;
+mod health;
+mod merge;
+mod metrics;
+mod orcid;
+mod publications;
+mod rate_limit;
+mod render;
+mod retry;
+mod ris;
+mod search;
+mod selectors;
+mod selftest;
+mod session;
+mod similarity;
+mod sources;
+#[cfg(not(target_arch = "wasm32"))]
+mod watch;
+mod yearly;
+
+pub use anonymize::{anonymize, anonymize_with_key};
+use author_id::to_author_id;
+pub use author_id::{is_valid_author_id, AuthorId};
+pub use batch::{fetch_many, serialize_batch, write_batch_yaml, write_ndjson, BatchResult};
+pub use benchmark::{benchmark, Benchmark, BenchmarkResult};
+pub use cache::MemoryCache;
+pub use check::{check_ids, check_ids_with_options, IdStatus};
+pub use client::ScholarClient;
+#[cfg(feature = "app")]
+pub use components::{ProfileSkeleton, PublicationList, ScholarProfile};
+#[cfg(feature = "crossref")]
+pub use crossref::{resolve_doi_crossref, resolve_doi_crossref_with_options, CrossrefOptions};
+pub use details::{
+    fetch_publication_detail, fetch_publication_details, PublicationDate, PublicationDetail,
+};
+pub use diff::AuthorDiff;
+pub use format::OutputFormat;
+pub use graph::{build_coauthor_graph, coauthor_graph_to_dot, CoauthorGraph, GraphNode};
+pub use health::{health_check, health_check_with_options, HealthState, HealthStatus};
+pub use merge::merge_author_info;
+pub use metrics::{compute_g_index, compute_h_index, compute_i_index};
+pub use orcid::orcid_from_profile;
+use publications::fetch_publications_capped;
+pub use publications::{
+    dedupe_publications, fetch_publications, publications_since, publications_since_with_options,
+    publications_url, sort_publications, Publication, PublicationSort,
+};
+pub use rate_limit::RateLimiter;
+pub use render::{render_sparkline, to_markdown_table};
+pub use retry::{retry_after, with_retry, RetryPolicy};
+use retry::{sleep, unix_now, Throttled};
+pub use ris::to_ris;
+pub use search::{
+    search_all_authors, search_authors, search_authors_page, search_authors_page_with_options,
+    search_authors_with_options, AuthorHit, SearchPage,
+};
+pub use selectors::{known_selectors, SelectorRole};
+pub use selftest::{selftest, selftest_with_options, SelectorCheck, SelftestReport};
+pub use session::ScholarSession;
+pub use similarity::{likely_same, similarity};
+pub use sources::{
+    AuthorSource, SourceBounds, SourceChain, SourceChainError, SourceFuture, SourcedAuthorInfo,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use watch::watch;
+pub use yearly::{
+    align_trajectories, dense_yearly_citations, estimated_career_start, has_current_year_data,
+    histogram_span, histogram_year_count, latest_data_year, mean_citations_last_n_years,
+    mean_citations_last_n_years_with_options, yearly_citations_normalized,
+    yearly_citations_with_gaps,
+};
 
 /// Represents the scraped author information
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthorInfo {
     /// Author name
-    name: String,
+    pub name: String,
     /// Total number of citations
-    total: usize,
+    pub total: usize,
     /// h-index of the author
-    h_index: usize,
+    pub h_index: usize,
     /// i10-index of the author
-    i10_index: usize,
+    pub i10_index: usize,
+    /// All rows of the summary table by label, as (all-time value, recent value)
+    ///
+    /// The named metrics above are taken from these rows. Rows Scholar may add
+    /// in the future show up here without a crate release.
+    #[serde(default)]
+    pub raw_metrics: BTreeMap<String, (usize, Option<usize>)>,
+    /// Links of the summary table's values, e.g. to the citing works on Scholar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub citation_links: Option<CitationLinks>,
     /// Yearly citation counts
     #[serde(rename = "years")]
-    yearly_citations: BTreeMap<usize, usize>,
+    pub yearly_citations: BTreeMap<usize, usize>,
+    /// Affiliation below the name, e.g. the university
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliation: Option<String>,
+    /// Domains of the verified email addresses
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub verified_email_domains: Vec<String>,
+    /// Whether the profile has a verified email and no disclaimer that it may not be the author's
+    ///
+    /// Unclaimed profiles compiled by Scholar itself show such a disclaimer.
+    #[serde(default)]
+    pub verified: bool,
+    /// Profile photo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<Url>,
+    /// External homepage linked in the profile header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<Url>,
+    /// Co-authors listed on the profile, sorted by name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coauthors: Vec<Coauthor>,
+    /// Publications, only fetched with [`FetchOptions::include_publications`],
+    /// sorted by year (newest first), citations (most first) and title
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub publications: Vec<Publication>,
+    /// Whether more publications exist than [`FetchOptions::max_publications`] allowed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub publications_truncated: bool,
+    /// Other labeled sections of the sidebar (e.g. "Public access"), as heading and raw text
+    ///
+    /// These are kept as text since they aren't stable enough to model.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra_metrics: BTreeMap<String, String>,
+    /// Where fields came from that aren't from the profile page, by field name
+    ///
+    /// Filled by [`merge_author_info`], empty for a profile fetched from Scholar.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<String, String>,
+    /// What couldn't be parsed with [`ParseMode::Lenient`] and was left empty, always empty when strict
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Links behind the values of the summary table, by row label like [`AuthorInfo::raw_metrics`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CitationLinks {
+    /// Links of the all-time values
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub all: BTreeMap<String, Url>,
+    /// Links of the recent values ("Since <year>")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub recent: BTreeMap<String, Url>,
+}
+
+/// A co-author listed in the sidebar of a profile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coauthor {
+    /// Google Scholar ID of the co-author
+    pub author_id: String,
+    /// Co-author name
+    pub name: String,
+    /// Affiliation shown below the name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliation: Option<String>,
+    /// Link to the co-author's profile
+    pub url: Url,
+    /// Thumbnail of the co-author, `None` for Scholar's placeholder avatar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub photo_url: Option<Url>,
+}
+
+impl AuthorInfo {
+    /// Returns the first verified email domain, if any
+    pub fn verified_email_domain(&self) -> Option<&str> {
+        self.verified_email_domains.first().map(String::as_str)
+    }
+}
+
+/// Options controlling how pages are fetched
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    /// Retry behaviour for throttled requests
+    pub retry: RetryPolicy,
+    /// Wait before each further request of an operation that needs several pages
+    pub politeness_delay: Duration,
+    /// Also fetch the full publication list, which takes one request per 100 publications
+    pub include_publications: bool,
+    /// Stop fetching publications after this many (the most cited ones)
+    ///
+    /// This applies per profile, also to each profile fetched by
+    /// [`build_coauthor_graph`], so a graph with publications costs up to
+    /// `max_nodes * (1 + max_publications / 100)` requests.
+    pub max_publications: Option<usize>,
+    /// URL of an HTTP(S) proxy for all requests (ignored in the browser)
+    pub proxy: Option<String>,
+    /// User-Agent header sent with every request (browsers may ignore it)
+    pub user_agent: Option<String>,
+    /// Timeout of a single request (ignored in the browser)
+    pub timeout: Option<Duration>,
+    /// Called after each request of an operation that needs several pages
+    pub progress: Option<ProgressCallback>,
+    /// Keep co-authors and publications in the order of the page instead of sorting them
+    pub raw_order: bool,
+    /// In-memory cache checked before and filled after fetching an author
+    pub cache: Option<MemoryCache>,
+    /// Cookie header sent with every request, e.g. `"SOCS=...; NID=..."` (ignored in the browser)
+    ///
+    /// See [`ScholarClient`] for how to get the cookies of a browser session.
+    pub cookies: Option<String>,
+    /// Cookie store receiving the cookies of responses and sending them back
+    #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+    pub cookie_store: Option<Arc<reqwest::cookie::Jar>>,
+    /// CSS selector of the element holding the author's name, `div#gsc_prf_in` if `None`
+    pub name_selector: Option<String>,
+    /// Applied to the text of the name element, e.g. to strip a badge label
+    pub name_filter: Option<NameFilter>,
+    /// Whether parts of a page that fail to parse fail the fetch or are left empty
+    pub parse_mode: ParseMode,
+    /// Fetch the profile page once more if the histogram is empty although the
+    /// author has at least [`EMPTY_HISTOGRAM_MIN_CITATIONS`] citations
+    ///
+    /// Scholar occasionally serves the page before the histogram is rendered.
+    /// The retry waits [`FetchOptions::politeness_delay`] and costs one request.
+    pub retry_empty_histogram: bool,
+    /// HTTP client reused for all requests, keeping connections alive between them
+    ///
+    /// If set, `proxy`, `user_agent`, `timeout`, `cookies`, `cookie_store` and
+    /// `accept_language` are ignored, they have to be configured on the client.
+    /// [`ScholarSession`] sets it up.
+    pub http_client: Option<reqwest::Client>,
+    /// Largest response body that is parsed, larger ones fail with [`ScraperError::ResponseTooLarge`]
+    ///
+    /// Profile pages are well below 1 MB, the limit caps the memory a broken
+    /// or hostile response can take. It is checked against `Content-Length`
+    /// and again while the body is read, so chunked responses are cut off too.
+    pub max_html_bytes: usize,
+    /// `Accept-Language` header sent with every request, the language of the `hl` parameter (`en`) if `None`
+    ///
+    /// Together with `hl` this keeps Scholar from serving the markup (and
+    /// consent redirects) of the language it guesses from the IP address.
+    pub accept_language: Option<String>,
+    /// Extra headers sent with every request to Scholar, e.g. `Sec-Fetch-*` headers
+    ///
+    /// They take precedence over the headers the crate sets, so they can
+    /// override `User-Agent`, `Accept-Language` and `Cookie` (also with a
+    /// shared `http_client`). Browsers refuse to send some headers, see
+    /// [`ScholarClient::with_header`] for adding them from strings.
+    pub headers: HeaderMap,
+    /// Leave out the current year's bar of the histogram, see [`AuthorInfo::without_current_year`]
+    pub drop_current_year: bool,
+    /// Limits the rate of all requests made with these options and their clones
+    ///
+    /// [`ScholarClient`] sets up one allowing a request per
+    /// `politeness_delay`, so clones of a client used by concurrent tasks
+    /// share a single limit.
+    pub rate_limiter: Option<RateLimiter>,
+    /// Order in which the publication list is requested
+    ///
+    /// This decides which publications `max_publications` keeps. The result
+    /// is still sorted with [`sort_publications`] unless `raw_order` is set.
+    pub publication_sort: PublicationSort,
+    /// CORS proxy the requests go through, followed by the encoded Scholar URL
+    ///
+    /// `https://api.codetabs.com/v1/proxy/?quest=` if `None`. Set it to a
+    /// proxy of your own, e.g. one that forwards cookies.
+    pub cors_proxy: Option<String>,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            retry: RetryPolicy::default(),
+            politeness_delay: Duration::from_secs(1),
+            include_publications: false,
+            max_publications: None,
+            proxy: None,
+            user_agent: None,
+            timeout: None,
+            progress: None,
+            raw_order: false,
+            cache: None,
+            cookies: None,
+            #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+            cookie_store: None,
+            name_selector: None,
+            name_filter: None,
+            parse_mode: ParseMode::Strict,
+            retry_empty_histogram: false,
+            http_client: None,
+            max_html_bytes: DEFAULT_MAX_HTML_BYTES,
+            accept_language: None,
+            headers: HeaderMap::new(),
+            drop_current_year: false,
+            rate_limiter: None,
+            publication_sort: PublicationSort::Citations,
+            cors_proxy: None,
+        }
+    }
+}
+
+impl FetchOptions {
+    /// Returns the CORS proxy of the requests, see [`FetchOptions::cors_proxy`]
+    fn cors_proxy(&self) -> &str {
+        self.cors_proxy.as_deref().unwrap_or(PROXY_URL)
+    }
+
+    /// Returns the CORS proxy as URL, e.g. to store cookies for it
+    #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+    fn cors_proxy_url(&self) -> Url {
+        Url::parse(self.cors_proxy())
+            .or_else(|_| Url::parse(PROXY_URL))
+            .expect("proxy URL is valid")
+    }
+
+    /// Passes a progress update to the callback, if there is one
+    fn report(&self, progress: Progress) {
+        if let Some(callback) = &self.progress {
+            (callback.0)(progress);
+        }
+    }
+}
+
+/// How [`parse_author_info`] handles parts of a page it can't parse
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParseMode {
+    /// Any unparsable part fails the whole page
+    #[default]
+    Strict,
+    /// Unparsable parts other than the name are logged and left empty (or zero)
+    ///
+    /// This recovers the rest of the data when Scholar changes one section.
+    Lenient,
+}
+
+impl ParseMode {
+    /// Passes a strict result through, or replaces a failure with the default value when lenient
+    ///
+    /// A replaced failure is logged and described in `warnings`.
+    fn recover<T: Default>(
+        self,
+        result: Result<T>,
+        part: &str,
+        warnings: &mut Vec<String>,
+    ) -> Result<T> {
+        match (self, result) {
+            (ParseMode::Lenient, Err(e)) => {
+                tracing::warn!("Failed to parse the {part}, leaving it empty: {e}");
+                warnings.push(format!("Failed to parse the {part}, left empty: {e}"));
+                Ok(T::default())
+            }
+            (_, result) => result,
+        }
+    }
+}
+
+/// Progress of an operation that needs several requests
+///
+/// Reported for the pages of [`fetch_publications`] and the profiles of
+/// [`build_coauthor_graph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    /// Number of items (publications or profiles) fetched so far
+    pub fetched: usize,
+    /// Estimated total number of items, `None` while unknown
+    pub total_estimate: Option<usize>,
+    /// Google Scholar ID of the author of the last request
+    pub current_author: String,
+}
+
+/// A callback receiving [`Progress`] updates, see [`FetchOptions::progress`]
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(Progress) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Wraps a closure as progress callback
+    pub fn new(callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+/// Post-processing of the extracted name, see [`FetchOptions::name_filter`]
+#[derive(Clone)]
+pub struct NameFilter(Arc<dyn Fn(&str) -> String + Send + Sync>);
+
+impl NameFilter {
+    /// Wraps a closure as name filter
+    pub fn new(filter: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(filter))
+    }
+}
+
+impl fmt::Debug for NameFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NameFilter")
+    }
+}
+
+/// Creates fetch options from environment variables, falling back to the defaults
+///
+/// * `SCHOLAR_PROXY` - URL of an HTTP(S) proxy, e.g. `http://proxy.local:3128`
+/// * `SCHOLAR_USER_AGENT` - User-Agent header sent with every request
+/// * `SCHOLAR_TIMEOUT` - Timeout of a single request, in seconds
+///
+/// Unset or empty variables keep the default. Explicit arguments take
+/// precedence over the environment by setting the fields on the result.
+pub fn options_from_env() -> FetchOptions {
+    let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+
+    let timeout = var("SCHOLAR_TIMEOUT").and_then(|value| match value.parse() {
+        Ok(secs) => Some(Duration::from_secs(secs)),
+        Err(_) => {
+            tracing::warn!("Ignoring invalid SCHOLAR_TIMEOUT {value:?}");
+            None
+        }
+    });
+
+    FetchOptions {
+        proxy: var("SCHOLAR_PROXY"),
+        user_agent: var("SCHOLAR_USER_AGENT"),
+        timeout,
+        ..FetchOptions::default()
+    }
+}
+
+/// Metadata about how a result was obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FetchMeta {
+    /// Unix timestamp (in seconds) of when the page was scraped
+    pub scraped_at: u64,
+    /// Whether the result was served from a cache instead of a live fetch
+    pub from_cache: bool,
+    /// Size of the profile page's body in bytes
+    #[serde(default)]
+    pub body_bytes: usize,
+}
+
+impl FetchMeta {
+    /// Returns the time elapsed since the page was scraped
+    pub fn age(&self) -> Duration {
+        unix_now().saturating_sub(Duration::from_secs(self.scraped_at))
+    }
+
+    /// Returns the Unix timestamp (in seconds) before which the profile shouldn't be fetched again
+    ///
+    /// See [`recommended_poll_interval`].
+    pub fn next_poll_at(&self) -> u64 {
+        self.scraped_at + MIN_POLL_INTERVAL_SECS
+    }
+
+    /// Checks whether the page was too small for a regular profile
+    ///
+    /// Profile pages are tens of kilobytes, a body below 2 KB is usually a
+    /// CAPTCHA, an error interstitial or a truncated response.
+    pub fn is_suspiciously_small(&self) -> bool {
+        self.body_bytes < 2048
+    }
+}
+
+/// Default of [`FetchOptions::max_html_bytes`] (8 MiB)
+pub const DEFAULT_MAX_HTML_BYTES: usize = 8 * 1024 * 1024;
+
+/// Total citations from which an empty histogram is taken as a rendering glitch,
+/// see [`FetchOptions::retry_empty_histogram`]
+pub const EMPTY_HISTOGRAM_MIN_CITATIONS: usize = 100;
+
+/// Minimum time between two fetches of the same profile, in seconds
+///
+/// Scholar recomputes citation counts a few times a week at most, so polling
+/// more often only adds load (and risks being blocked) without new data.
+pub const MIN_POLL_INTERVAL_SECS: u64 = 6 * 60 * 60;
+
+/// Returns the recommended minimum interval for polling a profile, see [`MIN_POLL_INTERVAL_SECS`]
+pub fn recommended_poll_interval() -> Duration {
+    Duration::from_secs(MIN_POLL_INTERVAL_SECS)
 }
 
 /// Custom error types for the scraper
-#[derive(Error, Debug, Serialize, Deserialize)]
-enum ScraperError {
+///
+/// Errors of the public functions are [`anyhow::Error`]s wrapping these
+/// variants. Convert them back with `ScraperError::from` to branch on the
+/// kind of failure, e.g. in a UI.
+#[derive(Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScraperError {
     #[error("Website not found. Check the ID.")]
     InvalidId,
+    #[error("Too many requests. Try again later.")]
+    RateLimited,
+    #[error("Google Scholar is temporarily unavailable. Try again later.")]
+    ServiceUnavailable,
     #[error("Failed to find the citation table on the website")]
     TableNotFound,
     #[error("Failed to find the name on the website")]
@@ -38,34 +551,440 @@ enum ScraperError {
     YearParseError(String),
     #[error("Failed to parse citation count: {0}")]
     CitationParseError(String),
+    #[error("Request failed: {0}")]
+    Request(String),
+    #[error("Timed out while fetching the {0}")]
+    Timeout(String),
+    #[error("Failed to serialize the result: {0}")]
+    Serialization(String),
+    #[error(
+        "Google asks for cookie consent first. Set FetchOptions::cookies, e.g. to CONSENT_COOKIE."
+    )]
+    ConsentRequired,
+    #[error("The response of {0} bytes exceeds the size limit")]
+    ResponseTooLarge(usize),
+    #[error("Network error ({0}): {1}")]
+    Network(NetworkErrorKind, String),
+}
+
+/// What kind of network failure a [`ScraperError::Network`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NetworkErrorKind {
+    /// The request or the connection timed out
+    Timeout,
+    /// The connection was reset or closed before the response was complete
+    ConnectionReset,
+    /// The host name of Scholar or the proxy didn't resolve
+    Dns,
+    /// The TLS handshake failed, e.g. because of an invalid certificate
+    Tls,
+}
+
+impl NetworkErrorKind {
+    /// Whether retrying may help, which is the case for timeouts and resets
+    ///
+    /// A name that doesn't resolve or a certificate that isn't trusted points
+    /// to the configuration (e.g. the proxy), so these aren't retried.
+    pub fn is_transient(self) -> bool {
+        matches!(
+            self,
+            NetworkErrorKind::Timeout | NetworkErrorKind::ConnectionReset
+        )
+    }
+}
+
+impl fmt::Display for NetworkErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NetworkErrorKind::Timeout => "timeout",
+            NetworkErrorKind::ConnectionReset => "connection reset",
+            NetworkErrorKind::Dns => "DNS",
+            NetworkErrorKind::Tls => "TLS",
+        })
+    }
 }
 
+impl From<anyhow::Error> for ScraperError {
+    /// Recovers the scraper error from an [`anyhow::Error`], other errors become [`ScraperError::Request`]
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<ScraperError>() {
+            Ok(error) => error,
+            Err(error) => ScraperError::Request(error.to_string()),
+        }
+    }
+}
+
+/// Google Scholar ID of the profile shown by the demo app, handy for examples and tests
+pub const EXAMPLE_AUTHOR_ID: &str = "H7sOPf8AAAAJ";
+
+/// Cookie recording a declined consent dialog, so Google serves pages directly
+///
+/// From EU locations Google redirects to `consent.google.com` until consent
+/// is given. Pass this in [`FetchOptions::cookies`] to skip the dialog, or
+/// use the `SOCS` cookie of a browser that already answered it.
+pub const CONSENT_COOKIE: &str = "SOCS=CAESEwgDEgk0ODE3Nzk3MjQaAmVuIAEaBgiA_LyaBg";
+
+/// Base URL of Google Scholar
+const SCHOLAR_URL: &str = "https://scholar.google.com";
+
+/// Interface language requested from Google Scholar, which the label matching relies on
+const SCHOLAR_LANGUAGE: &str = "en";
+
+/// CORS proxy used to reach Google Scholar, followed by the encoded target URL
+const PROXY_URL: &str = "https://api.codetabs.com/v1/proxy/?quest=";
+
 /// Fetches the HTML content of the author's Google Scholar page
 ///
 /// # Arguments
 ///
 /// * `authorid` - The Google Scholar ID of the author
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `Result<(Html, usize)>` - The parsed HTML document and the size of the body in bytes
+async fn fetch_page(authorid: &AuthorId, options: &FetchOptions) -> Result<(Html, usize)> {
+    fetch_html_sized(&profile_url(authorid), options).await
+}
+
+/// Builds the shareable URL of an author's Google Scholar profile
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+///
+/// # Returns
+///
+/// * `String` - The profile URL, e.g. `https://scholar.google.com/citations?user=<id>&hl=en`
+pub fn profile_url(author_id: &str) -> String {
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("user", author_id)
+        .append_pair("hl", SCHOLAR_LANGUAGE)
+        .finish();
+    format!("{SCHOLAR_URL}/citations?{query}")
+}
+
+/// Fetches a Google Scholar page through the proxy and parses it
+///
+/// Throttled requests (HTTP 429) and temporarily unavailable responses are
+/// retried according to `options.retry`, honoring the `Retry-After` header
+/// when the server sends one.
+///
+/// # Arguments
+///
+/// * `url` - The Google Scholar URL to fetch
+/// * `options` - The fetch options
 ///
 /// # Returns
 ///
 /// * `Result<Html>` - The parsed HTML document
-async fn fetch_page(authorid: &str) -> Result<Html> {
-    let response = reqwest::get(&format!(
-        "https://api.codetabs.com/v1/proxy/?quest=https://scholar.google.com/citations?user={authorid}",
-    ))
-    .await?;
+async fn fetch_html(url: &str, options: &FetchOptions) -> Result<Html> {
+    let (document, _) = fetch_html_sized(url, options).await?;
+    Ok(document)
+}
+
+/// Same as [`fetch_html`], but also returns the size of the body in bytes
+async fn fetch_html_sized(url: &str, options: &FetchOptions) -> Result<(Html, usize)> {
+    let url = proxied_url(url, options);
+    let client = http_client(options)?;
+
+    with_retry(&options.retry, |attempt| {
+        fetch_html_attempt(&client, &url, attempt, options)
+    })
+    .await
+}
+
+/// Sends a single request for [`fetch_html_sized`], failing with [`Throttled`] if it should be retried
+async fn fetch_html_attempt(
+    client: &reqwest::Client,
+    url: &str,
+    attempt: usize,
+    options: &FetchOptions,
+) -> Result<(Html, usize)> {
+    if let Some(rate_limiter) = &options.rate_limiter {
+        rate_limiter.acquire().await;
+    }
 
-    match response.status() {
+    // Attribute names follow the OpenTelemetry HTTP client conventions
+    let span = tracing::info_span!(
+        "GET",
+        otel.kind = "client",
+        otel.status_code = tracing::field::Empty,
+        http.method = "GET",
+        http.url = %url,
+        http.status_code = tracing::field::Empty,
+        http.resend_count = attempt,
+    );
+    let response = client
+        .get(url)
+        .headers(options.headers.clone())
+        .send()
+        .instrument(span.clone())
+        .await
+        .inspect_err(|_| {
+            span.record("otel.status_code", "ERROR");
+        })?;
+    let headers = response.headers().clone();
+    span.record("http.status_code", response.status().as_u16());
+    if response.status() != StatusCode::OK {
+        span.record("otel.status_code", "ERROR");
+    }
+
+    let error = match response.status() {
         StatusCode::OK => {
-            let html_content = response.text().await?;
-            let document = Html::parse_document(&html_content);
-            Ok(document)
+            let html_content = read_body(response, options).instrument(span).await?;
+            let document = parse_html(&html_content, options)?;
+            if is_consent_page(&document) {
+                return Err(ScraperError::ConsentRequired.into());
+            }
+            if !is_unavailable_page(&document) {
+                return Ok((document, html_content.len()));
+            }
+            ScraperError::ServiceUnavailable
         }
-        _ => Err(ScraperError::InvalidId.into()),
+        StatusCode::TOO_MANY_REQUESTS => ScraperError::RateLimited,
+        StatusCode::SERVICE_UNAVAILABLE => ScraperError::ServiceUnavailable,
+        _ => return Err(ScraperError::InvalidId.into()),
+    };
+    Err(Throttled::new(error, &headers).into())
+}
+
+/// Fails with [`ScraperError::ResponseTooLarge`] if a body exceeds `options.max_html_bytes`
+fn check_html_size(bytes: usize, options: &FetchOptions) -> Result<()> {
+    if bytes > options.max_html_bytes {
+        return Err(ScraperError::ResponseTooLarge(bytes).into());
     }
+    Ok(())
 }
 
-/// Extracts the author's main citation information (Name, total, h-index, i10-index)
+/// Reads the body of a response, failing as soon as it exceeds `options.max_html_bytes`
+///
+/// The body is read in chunks rather than at once, so a response without
+/// `Content-Length` (or with a wrong one) can't take more memory than the
+/// limit. Invalid UTF-8 is replaced, Scholar serves UTF-8 only.
+async fn read_body(response: reqwest::Response, options: &FetchOptions) -> Result<String> {
+    if let Some(length) = response.content_length() {
+        check_html_size(length as usize, options)?;
+    }
+
+    let mut body = Vec::new();
+    let mut chunks = response.bytes_stream();
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk?;
+        check_html_size(body.len() + chunk.len(), options)?;
+        body.extend_from_slice(&chunk);
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Parses an HTML document unless it exceeds `options.max_html_bytes`
+fn parse_html(html: &str, options: &FetchOptions) -> Result<Html> {
+    check_html_size(html.len(), options)?;
+    Ok(Html::parse_document(html))
+}
+
+/// Returns the URL of a Google Scholar page behind the CORS proxy
+fn proxied_url(url: &str, options: &FetchOptions) -> String {
+    format!(
+        "{}{}",
+        options.cors_proxy(),
+        form_urlencoded::byte_serialize(url.as_bytes()).collect::<String>()
+    )
+}
+
+/// Returns the shared HTTP client of the options or builds one for them
+fn http_client(options: &FetchOptions) -> Result<reqwest::Client> {
+    if let Some(client) = &options.http_client {
+        return Ok(client.clone());
+    }
+
+    let mut builder = reqwest::Client::builder();
+    let mut headers = HeaderMap::new();
+
+    if let Some(user_agent) = &options.user_agent {
+        builder = builder.user_agent(user_agent);
+    }
+    let language = options
+        .accept_language
+        .as_deref()
+        .unwrap_or(SCHOLAR_LANGUAGE);
+    headers.insert(reqwest::header::ACCEPT_LANGUAGE, language.parse()?);
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        if let Some(proxy) = &options.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        if let Some(timeout) = options.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(cookies) = &options.cookies {
+            headers.insert(reqwest::header::COOKIE, cookies.parse()?);
+        }
+        #[cfg(feature = "cookies")]
+        if let Some(cookie_store) = &options.cookie_store {
+            builder = builder.cookie_provider(cookie_store.clone());
+        }
+    }
+
+    Ok(builder.default_headers(headers).build()?)
+}
+
+/// Checks whether a page is Scholar's "temporarily unavailable" or over-quota interstitial
+///
+/// These are served with status 200 but lack the regular page body (`#gs_bdy`).
+fn is_unavailable_page(document: &Html) -> bool {
+    const MARKERS: [&str; 3] = ["temporarily unavailable", "service unavailable", "quota"];
+    let body_selector = Selector::parse(selectors::PAGE_BODY).unwrap();
+
+    if document.select(&body_selector).next().is_some() {
+        return false;
+    }
+
+    let text = document
+        .root_element()
+        .text()
+        .collect::<String>()
+        .to_lowercase();
+    MARKERS.iter().any(|marker| text.contains(marker))
+}
+
+/// Checks whether a page is Google's cookie consent dialog instead of the requested page
+///
+/// Google redirects there from EU locations, and the proxy follows the
+/// redirect. The dialog's forms submit to `consent.google.com`.
+fn is_consent_page(document: &Html) -> bool {
+    let body_selector = Selector::parse(selectors::PAGE_BODY).unwrap();
+    let form_selector = Selector::parse(selectors::CONSENT_FORM).unwrap();
+
+    document.select(&body_selector).next().is_none()
+        && document.select(&form_selector).next().is_some()
+}
+
+/// Checks whether a page carries Google's robot check CAPTCHA
+fn is_robot_check_page(document: &Html) -> bool {
+    let robot_check_selector = Selector::parse(selectors::ROBOT_CHECK).unwrap();
+    document.select(&robot_check_selector).next().is_some()
+}
+
+/// Checks whether an extracted name is an artifact of a robot check page
+///
+/// A safety net for CAPTCHA variants that neither [`is_unavailable_page`]
+/// nor [`is_robot_check_page`] recognize: their headings end up in the name
+/// slot. Only whole phrases of the interstitial count, so names like
+/// "Sorrell Verify" aren't mistaken for one.
+fn is_robot_check_name(name: &str) -> bool {
+    const PHRASES: [&str; 5] = [
+        "i'm not a robot",
+        "unusual traffic from your computer network",
+        "our systems have detected unusual traffic",
+        "please show you're not a robot",
+        "verify you're a human",
+    ];
+
+    let name = name.trim().to_lowercase().replace('\u{2019}', "'");
+    name.is_empty() || PHRASES.iter().any(|phrase| name.contains(phrase))
+}
+
+/// Returns the whitespace-trimmed text content of an element
+fn element_text(element: ElementRef) -> String {
+    element.text().collect::<String>().trim().to_string()
+}
+
+/// Returns the value of a query parameter in a (relative) URL
+fn query_param(href: &str, key: &str) -> Option<String> {
+    let (_, query) = href.split_once('?')?;
+    form_urlencoded::parse(query.as_bytes())
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.into_owned())
+}
+
+/// Resolves a (relative) href from a Google Scholar page to an absolute URL
+///
+/// Empty hrefs yield `None`. Invalid URLs are logged and dropped, so a single
+/// broken link doesn't fail the parse.
+fn resolve_url(href: &str) -> Option<Url> {
+    if href.trim().is_empty() {
+        return None;
+    }
+
+    match Url::parse(SCHOLAR_URL).and_then(|base| base.join(href)) {
+        Ok(url) => Some(url),
+        Err(e) => {
+            tracing::warn!("Dropping invalid URL {href:?}: {e}");
+            None
+        }
+    }
+}
+
+/// A row of the summary table as (label, all-time value, recent value)
+type SummaryRow = (String, usize, Option<usize>);
+
+/// Extracts the author's name
+///
+/// The name is the text of `options.name_selector` with collapsed whitespace.
+/// Only the element's own text counts, so a verification badge inside it is
+/// dropped. If the name is split across child elements instead, their texts
+/// are joined by spaces, skipping badges (children with a `title`). The name
+/// is then passed through `options.name_filter`, if set.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `Result<String>` - The name of the author
+fn extract_name(document: &Html, options: &FetchOptions) -> Result<String> {
+    let selector = options.name_selector.as_deref().unwrap_or(selectors::NAME);
+    let name_selector = Selector::parse(selector)
+        .map_err(|_| ScraperError::ParseError(format!("invalid name selector {selector:?}")))?;
+
+    let element = document
+        .select(&name_selector)
+        .next()
+        .ok_or(ScraperError::NameNotFound)?;
+    let own_text = element
+        .children()
+        .filter_map(|child| child.value().as_text())
+        .map(|text| &**text)
+        .collect::<String>();
+    let name = if own_text.trim().is_empty() {
+        element
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|child| child.value().attr("title").is_none())
+            .map(element_text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        own_text
+    };
+    let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    Ok(match &options.name_filter {
+        Some(filter) => (filter.0)(&name),
+        None => name,
+    })
+}
+
+/// Extracts only the summary table (total, h-index, i10-index)
+///
+/// This skips the name, histogram and everything else, for callers that only
+/// monitor the headline metrics. The numbers are the same as in [`AuthorInfo`].
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Result<(usize, usize, usize)>` - A tuple containing (total citations, h-index, i10-index)
+pub fn extract_metrics_only(document: &Html) -> Result<(usize, usize, usize)> {
+    metrics_from_rows(&extract_summary_rows(document)?)
+}
+
+/// Extracts all labeled rows of the summary table in page order
 ///
 /// # Arguments
 ///
@@ -73,44 +992,170 @@ async fn fetch_page(authorid: &str) -> Result<Html> {
 ///
 /// # Returns
 ///
-/// * `Result<(String, usize, usize, usize)>` - A tuple containing (name, total citations, h-index, i10-index)
-fn extract_author_info(document: &Html) -> Result<(String, usize, usize, usize)> {
-    let table_selector = Selector::parse("table#gsc_rsb_st").unwrap();
-    let row_selector = Selector::parse("tr > td:nth-child(2)").unwrap();
-    let name_selector = Selector::parse("div#gsc_prf_in").unwrap();
+/// * `Result<Vec<SummaryRow>>` - The rows as (label, all-time value, recent value)
+fn extract_summary_rows(document: &Html) -> Result<Vec<SummaryRow>> {
+    if is_mobile_layout(document) {
+        return extract_mobile_summary_rows(document);
+    }
+
+    let table_selector = Selector::parse(selectors::SUMMARY_TABLE).unwrap();
+    let row_selector = Selector::parse("tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
 
     let table = document
         .select(&table_selector)
         .next()
         .ok_or(ScraperError::TableNotFound)?;
 
-    let values: Result<Vec<usize>, _> = table
+    // Each metric row is a label cell followed by one or two data cells
+    // ("All" and, on most profiles, "Since <year>"). Rows without a data cell
+    // (e.g. the header) are skipped, and a missing second column just leaves
+    // the recent value empty.
+    let rows: Result<Vec<SummaryRow>, ScraperError> = table
         .select(&row_selector)
-        .map(|element| {
-            element
-                .inner_html()
-                .parse()
-                .map_err(|_| ScraperError::ParseError(element.inner_html()))
+        .filter_map(|row| {
+            let mut cells = row.select(&cell_selector);
+            Some((cells.next()?, cells.next()?, cells.next()))
+        })
+        .map(|(label, all, recent)| {
+            Ok((
+                element_text(label),
+                parse_summary_value(all)?,
+                recent.map(parse_summary_value).transpose()?,
+            ))
+        })
+        .collect();
+
+    Ok(rows?)
+}
+
+/// Extracts the summary rows of the mobile layout, see [`extract_summary_rows`]
+///
+/// Mobile user agents get the metrics as a list of rows, each a label span
+/// followed by one or two value spans.
+fn extract_mobile_summary_rows(document: &Html) -> Result<Vec<SummaryRow>> {
+    let row_selector = Selector::parse(selectors::MOBILE_SUMMARY_ROWS).unwrap();
+    let label_selector = Selector::parse(selectors::MOBILE_SUMMARY_LABEL).unwrap();
+    let value_selector = Selector::parse(selectors::MOBILE_SUMMARY_VALUES).unwrap();
+
+    let rows: Result<Vec<SummaryRow>, ScraperError> = document
+        .select(&row_selector)
+        .filter_map(|row| {
+            let label = row.select(&label_selector).next()?;
+            let mut values = row.select(&value_selector);
+            Some((label, values.next()?, values.next()))
+        })
+        .map(|(label, all, recent)| {
+            Ok((
+                element_text(label),
+                parse_summary_value(all)?,
+                recent.map(parse_summary_value).transpose()?,
+            ))
         })
         .collect();
 
-    let values = values?;
+    Ok(rows?)
+}
+
+/// Parses a value of the summary table
+fn parse_summary_value(value: ElementRef) -> Result<usize, ScraperError> {
+    element_text(value)
+        .parse()
+        .map_err(|_| ScraperError::ParseError(value.inner_html()))
+}
+
+/// Checks whether a page is the compact layout served to mobile user agents
+///
+/// It has the mobile summary rows instead of the summary table. Setting a
+/// mobile [`FetchOptions::user_agent`] gets this layout.
+fn is_mobile_layout(document: &Html) -> bool {
+    let table_selector = Selector::parse(selectors::SUMMARY_TABLE).unwrap();
+    let mobile_selector = Selector::parse(selectors::MOBILE_SUMMARY_ROWS).unwrap();
 
-    if values.len() < 3 {
-        return Err(ScraperError::InsufficientData(values.len()).into());
+    document.select(&table_selector).next().is_none()
+        && document.select(&mobile_selector).next().is_some()
+}
+
+/// Extracts the links of the summary table's values
+///
+/// Only web links count, the script placeholders (`javascript:void(0)`) of
+/// the current layout are skipped.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Option<CitationLinks>` - The links by row label, `None` if no value is a link
+fn extract_citation_links(document: &Html) -> Option<CitationLinks> {
+    let table_selector = Selector::parse(selectors::SUMMARY_TABLE).unwrap();
+    let row_selector = Selector::parse("tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+    let link_selector = Selector::parse("a[href]").unwrap();
+
+    let link = |cell: ElementRef| {
+        cell.select(&link_selector)
+            .filter_map(|link| link.value().attr("href"))
+            .filter_map(resolve_url)
+            .find(|url| matches!(url.scheme(), "http" | "https"))
+    };
+
+    let mut links = CitationLinks::default();
+    for row in document
+        .select(&table_selector)
+        .flat_map(|table| table.select(&row_selector))
+    {
+        let mut cells = row.select(&cell_selector);
+        let (Some(label), Some(all), recent) = (cells.next(), cells.next(), cells.next()) else {
+            continue;
+        };
+        let label = element_text(label);
+        if let Some(url) = link(all) {
+            links.all.insert(label.clone(), url);
+        }
+        if let Some(url) = recent.and_then(link) {
+            links.recent.insert(label, url);
+        }
     }
 
-    let name = document
-        .select(&name_selector)
-        .next()
-        .ok_or(ScraperError::NameNotFound)?
-        .inner_html();
+    (links != CitationLinks::default()).then_some(links)
+}
 
-    Ok((name, values[0], values[1], values[2]))
+/// Picks total citations, h-index and i10-index from the summary rows
+///
+/// The values are mapped by their labels, so reordered or additional rows
+/// don't shift them. Values with an unknown label (e.g. other locales) are
+/// taken from the rows no other value claimed, in the usual order of total,
+/// h-index, i10-index.
+fn metrics_from_rows(rows: &[SummaryRow]) -> Result<(usize, usize, usize)> {
+    let position = |wanted: &str| {
+        rows.iter()
+            .position(|(label, _, _)| label.eq_ignore_ascii_case(wanted))
+    };
+    let labeled = ["Citations", "h-index", "i10-index"].map(position);
+
+    let mut unclaimed = (0..rows.len()).filter(|index| !labeled.contains(&Some(*index)));
+    let mut values = [0; 3];
+    for (value, index) in values.iter_mut().zip(labeled) {
+        let index = index
+            .or_else(|| unclaimed.next())
+            .ok_or(ScraperError::InsufficientData(rows.len()))?;
+        *value = rows[index].1;
+    }
+
+    let [total, h_index, i10_index] = values;
+    Ok((total, h_index, i10_index))
 }
 
 /// Extracts the yearly citation counts
 ///
+/// Works on the profile page as well as the embeddable widget and the older
+/// layout, whichever histogram is found first. If every bar carries an
+/// accessibility label like "123 citations in 2020", the labels are used
+/// instead of the visible year and count spans, as they don't depend on the
+/// layout.
+///
 /// # Arguments
 ///
 /// * `document` - The parsed HTML document of the author's page
@@ -118,53 +1163,605 @@ fn extract_author_info(document: &Html) -> Result<(String, usize, usize, usize)>
 /// # Returns
 ///
 /// * `Result<BTreeMap<usize, usize>>` - A map of years to citation counts
-fn extract_citations(document: &Html) -> Result<BTreeMap<usize, usize>> {
-    let div_selector = Selector::parse("div.gsc_md_hist_w > div.gsc_md_hist_b").unwrap();
-    let year_selector = Selector::parse("span.gsc_g_t").unwrap();
-    let citation_selector = Selector::parse("a.gsc_g_a > span.gsc_g_al").unwrap();
+pub fn extract_citations(document: &Html) -> Result<BTreeMap<usize, usize>> {
+    if let Some(yearly_citations) = extract_labeled_citations(document) {
+        return Ok(yearly_citations);
+    }
+    if is_mobile_layout(document) {
+        return extract_mobile_citations(document);
+    }
 
-    let div = document
-        .select(&div_selector)
-        .next()
+    let year_selector = Selector::parse(selectors::HISTOGRAM_YEARS).unwrap();
+    let bar_selector = Selector::parse(selectors::HISTOGRAM_BARS).unwrap();
+    let citation_selector = Selector::parse(selectors::HISTOGRAM_COUNTS).unwrap();
+
+    let (year_container, bar_container) = selectors::HISTOGRAM_LAYOUTS
+        .iter()
+        .find_map(|(years, bars)| {
+            let years = document.select(&Selector::parse(years).unwrap()).next()?;
+            let bars = document.select(&Selector::parse(bars).unwrap()).next()?;
+            Some((years, bars))
+        })
         .ok_or(ScraperError::TableNotFound)?;
 
-    let years = div.select(&year_selector);
-    let citations = div.select(&citation_selector);
+    let years = year_container.select(&year_selector);
+    let bars = bar_container.select(&bar_selector);
 
     years
-        .zip(citations)
-        .map(|(y, c)| {
-            let year = y
-                .inner_html()
+        .zip(bars)
+        .map(|(y, bar)| {
+            let year = element_text(y)
                 .parse()
                 .map_err(|_| ScraperError::YearParseError(y.inner_html()))?;
-            let citations = c
-                .inner_html()
-                .parse()
-                .map_err(|_| ScraperError::CitationParseError(c.inner_html()))?;
+            let citations = bar_citations(bar, &citation_selector)
+                .ok_or_else(|| ScraperError::CitationParseError(bar.inner_html()))?;
             Ok((year, citations))
         })
         .collect()
 }
 
+/// Extracts the yearly citation counts of the mobile layout, see [`extract_citations`]
+///
+/// Each bar of the mobile histogram holds its own year and count, so no
+/// pairing of separate year labels and bars is needed.
+fn extract_mobile_citations(document: &Html) -> Result<BTreeMap<usize, usize>> {
+    let bar_selector = Selector::parse(selectors::MOBILE_HISTOGRAM_BARS).unwrap();
+    let year_selector = Selector::parse(selectors::MOBILE_HISTOGRAM_YEAR).unwrap();
+    let count_selector = Selector::parse(selectors::MOBILE_HISTOGRAM_COUNT).unwrap();
+
+    document
+        .select(&bar_selector)
+        .map(|bar| {
+            let year = bar
+                .select(&year_selector)
+                .next()
+                .and_then(|year| element_text(year).parse().ok())
+                .ok_or_else(|| ScraperError::YearParseError(bar.inner_html()))?;
+            let citations = bar
+                .select(&count_selector)
+                .next()
+                .and_then(|count| leading_number(&element_text(count)))
+                .ok_or_else(|| ScraperError::CitationParseError(bar.inner_html()))?;
+            Ok((year, citations))
+        })
+        .collect()
+}
+
+/// Reads the yearly citation counts from the `aria-label` of the histogram bars
+///
+/// Returns `None` unless there are bars and all of their labels match, so a
+/// partially labeled histogram falls back to the spans.
+fn extract_labeled_citations(document: &Html) -> Option<BTreeMap<usize, usize>> {
+    static LABEL: OnceLock<Regex> = OnceLock::new();
+    let label = LABEL.get_or_init(|| Regex::new(r"(\d[\d,]*) citations? in (\d{4})").unwrap());
+    let bar_selector = Selector::parse(selectors::HISTOGRAM_BARS).unwrap();
+
+    let mut yearly_citations = BTreeMap::new();
+    for bar in document.select(&bar_selector) {
+        let captures = label.captures(bar.value().attr("aria-label")?)?;
+        let citations = captures[1].replace(',', "").parse().ok()?;
+        let year = captures[2].parse().ok()?;
+        yearly_citations.insert(year, citations);
+    }
+
+    (!yearly_citations.is_empty()).then_some(yearly_citations)
+}
+
+/// Parses the yearly citation counts from the HTML of a profile page
+///
+/// For pages fetched by other means. This is [`extract_citations`] on the
+/// parsed HTML, which may also be just the fragment holding the histogram.
+///
+/// # Arguments
+///
+/// * `html` - The HTML of the author's page
+///
+/// # Returns
+///
+/// * `Result<BTreeMap<usize, usize>>` - A map of years to citation counts
+pub fn parse_citations(html: &str) -> Result<BTreeMap<usize, usize>> {
+    extract_citations(&Html::parse_document(html))
+}
+
+/// Reads the citation count of a histogram bar
+///
+/// The count is normally the text of the bar's `span.gsc_g_al`. When that is
+/// missing or empty, it is taken from the bar's `title` or `aria-label`
+/// attribute instead (e.g. "123" or "123 citations").
+fn bar_citations(bar: ElementRef, citation_selector: &Selector) -> Option<usize> {
+    let text = bar
+        .select(citation_selector)
+        .next()
+        .map(element_text)
+        .unwrap_or_default();
+    if !text.is_empty() {
+        return text.parse().ok();
+    }
+
+    ["title", "aria-label"]
+        .into_iter()
+        .filter_map(|attr| bar.value().attr(attr))
+        .find_map(leading_number)
+}
+
+/// Parses the first number in a text, ignoring thousands separators
+fn leading_number(text: &str) -> Option<usize> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+/// Extracts the verified email domains from the profile header
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Vec<String>` - The domains, empty if the profile has no verified email
+fn extract_email_domains(document: &Html) -> Vec<String> {
+    let email_selector = Selector::parse(selectors::EMAIL_DOMAINS).unwrap();
+
+    document
+        .select(&email_selector)
+        .next()
+        .map(|element| parse_email_domains(&element_text(element)))
+        .unwrap_or_default()
+}
+
+/// Checks whether the profile carries a disclaimer that it may not be the author's
+fn has_disclaimer(document: &Html) -> bool {
+    let disclaimer_selector = Selector::parse(selectors::DISCLAIMER).unwrap();
+    document.select(&disclaimer_selector).next().is_some()
+}
+
+/// Parses a line like "Verified email at a.edu and b.org - Homepage" into its domains
+fn parse_email_domains(text: &str) -> Vec<String> {
+    let Some((_, domains)) = text.split_once(" at ") else {
+        return Vec::new();
+    };
+    let domains = domains.split(" - ").next().unwrap_or_default();
+
+    domains
+        .split(',')
+        .flat_map(|part| part.split(" and "))
+        .map(str::trim)
+        .filter(|domain| !domain.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Extracts the profile photo
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Option<Url>` - The absolute URL of the photo
+fn extract_photo_url(document: &Html) -> Option<Url> {
+    let photo_selector = Selector::parse(selectors::PHOTO).unwrap();
+
+    document
+        .select(&photo_selector)
+        .next()
+        .and_then(|element| element.value().attr("src"))
+        .and_then(resolve_url)
+}
+
+/// Extracts the affiliation line below the name
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Option<String>` - The affiliation with collapsed whitespace, `None` if missing or empty
+fn extract_affiliation(document: &Html) -> Option<String> {
+    let affiliation_selector = Selector::parse(selectors::AFFILIATION).unwrap();
+
+    let element = document.select(&affiliation_selector).next()?;
+    let affiliation = element.text().collect::<Vec<_>>().join(" ");
+    let affiliation = affiliation.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!affiliation.is_empty()).then_some(affiliation)
+}
+
+/// Extracts the external homepage linked in the profile header
+///
+/// Links pointing back to Google Scholar are ignored.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Option<Url>` - The absolute URL of the homepage
+fn extract_homepage(document: &Html) -> Option<Url> {
+    let link_selector = Selector::parse(selectors::HOMEPAGE).unwrap();
+
+    document
+        .select(&link_selector)
+        .filter_map(|element| element.value().attr("href"))
+        .filter_map(resolve_url)
+        .find(|url| !is_scholar_url(url))
+}
+
+/// Checks whether a URL points to Google Scholar itself
+fn is_scholar_url(url: &Url) -> bool {
+    url.host_str()
+        .is_some_and(|host| host.starts_with("scholar.google."))
+}
+
+/// Extracts the labeled sidebar sections besides the citations and co-authors
+///
+/// Each section is keyed by its heading, the value is the rest of its text
+/// with collapsed whitespace. Sections without a heading are skipped.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `BTreeMap<String, String>` - The section texts by heading
+fn extract_extra_metrics(document: &Html) -> BTreeMap<String, String> {
+    let section_selector = Selector::parse(selectors::SIDEBAR_SECTIONS).unwrap();
+    let heading_selector = Selector::parse("h3").unwrap();
+
+    document
+        .select(&section_selector)
+        .filter_map(|section| {
+            let heading = section.select(&heading_selector).next()?;
+            let label = element_text(heading);
+            let value = section
+                .descendants()
+                .filter(|node| {
+                    !node
+                        .ancestors()
+                        .any(|ancestor| ancestor.id() == heading.id())
+                })
+                .filter_map(|node| node.value().as_text())
+                .flat_map(|text| text.split_whitespace())
+                .collect::<Vec<_>>()
+                .join(" ");
+            (!label.is_empty() && !value.is_empty()).then_some((label, value))
+        })
+        .collect()
+}
+
+/// Extracts the co-authors listed in the sidebar
+///
+/// Entries without a valid profile link are skipped.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Vec<Coauthor>` - The co-authors in the order shown on the page
+fn extract_coauthors(document: &Html) -> Vec<Coauthor> {
+    let entry_selector = Selector::parse(selectors::COAUTHORS).unwrap();
+    let link_selector = Selector::parse("a").unwrap();
+    let affiliation_selector = Selector::parse(selectors::COAUTHOR_AFFILIATION).unwrap();
+    let photo_selector = Selector::parse(selectors::COAUTHOR_PHOTO).unwrap();
+
+    document
+        .select(&entry_selector)
+        .filter_map(|entry| {
+            let link = entry.select(&link_selector).next()?;
+            let href = link.value().attr("href")?;
+            // The thumbnail is next to the description, inside the same card
+            let photo_url = entry
+                .parent()
+                .and_then(ElementRef::wrap)
+                .and_then(|card| card.select(&photo_selector).next())
+                .and_then(|photo| photo.value().attr("src"))
+                .and_then(resolve_url)
+                .filter(|url| !url.path().contains("avatar_scholar"));
+
+            Some(Coauthor {
+                author_id: query_param(href, "user")?,
+                name: element_text(link),
+                affiliation: entry
+                    .select(&affiliation_selector)
+                    .next()
+                    .map(element_text)
+                    .filter(|text| !text.is_empty()),
+                url: resolve_url(href)?,
+                photo_url,
+            })
+        })
+        .collect()
+}
+
 /// Main function to run the scraper
 ///
 /// This function fetches the author's page,
 /// extracts citation information, and returns the results as YAML.
-pub async fn fetch_info(author_id: String) -> Result<String> {
-    let document = fetch_page(&author_id).await?;
+pub async fn fetch_info(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+) -> Result<String> {
+    fetch_info_with_options(author_id, &FetchOptions::default()).await
+}
+
+/// Same as [`fetch_info`], but with custom fetch options
+pub async fn fetch_info_with_options(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<String> {
+    let (res, _) = fetch_info_with_meta(author_id, options).await?;
+    Ok(res)
+}
 
-    let (name, total, h_index, i10_index) = extract_author_info(&document)?;
-    let yearly_citations = extract_citations(&document)?;
+/// Same as [`fetch_info_with_options`], but also returns metadata about the fetch
+pub async fn fetch_info_with_meta(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<(String, FetchMeta)> {
+    let (author_info, meta) = fetch_author_info_with_meta(author_id, options).await?;
 
-    let author_info = AuthorInfo {
+    let res = OutputFormat::Yaml.serialize(&author_info)?;
+    Ok((res, meta))
+}
+
+/// Fetches the author's page and extracts only the summary metrics
+///
+/// See [`extract_metrics_only`].
+pub async fn fetch_metrics(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<(usize, usize, usize)> {
+    let (document, _) = fetch_page(&to_author_id(author_id)?, options).await?;
+    extract_metrics_only(&document)
+}
+
+/// Fetches the author's page and extracts all information into an [`AuthorInfo`]
+pub async fn fetch_author_info(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<AuthorInfo> {
+    let (author_info, _) = fetch_author_info_with_meta(author_id, options).await?;
+    Ok(author_info)
+}
+
+/// Same as [`fetch_author_info`], but also returns metadata about the fetch
+///
+/// If `options.cache` holds a fresh entry for the author, it is returned
+/// without a request.
+pub async fn fetch_author_info_with_meta(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<(AuthorInfo, FetchMeta)> {
+    let author_id = to_author_id(author_id)?;
+    fetch_author_info_tracked(&author_id, options, &Mutex::new(PHASE_PROFILE)).await
+}
+
+/// Same as [`fetch_author_info`], but gives up once `deadline` has passed
+///
+/// The deadline covers the whole operation, including all pages of
+/// publications. When it expires, the in-flight request is cancelled and
+/// [`ScraperError::Timeout`] names the phase that was running.
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `deadline` - The maximum duration of the whole fetch
+/// * `options` - The fetch options
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn fetch_author_info_deadline(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    deadline: Duration,
+    options: &FetchOptions,
+) -> Result<AuthorInfo> {
+    let author_id = to_author_id(author_id)?;
+    let phase = Mutex::new(PHASE_PROFILE);
+    match tokio::time::timeout(
+        deadline,
+        fetch_author_info_tracked(&author_id, options, &phase),
+    )
+    .await
+    {
+        Ok(result) => result.map(|(author_info, _)| author_info),
+        Err(_) => {
+            let phase = *phase
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            Err(ScraperError::Timeout(phase.to_string()).into())
+        }
+    }
+}
+
+/// Phase of fetching the profile page, as reported by [`ScraperError::Timeout`]
+const PHASE_PROFILE: &str = "profile page";
+/// Phase of fetching the publication list, as reported by [`ScraperError::Timeout`]
+const PHASE_PUBLICATIONS: &str = "publications";
+
+/// Fetches an author with the cache, recording the running phase in `phase`
+async fn fetch_author_info_tracked(
+    author_id: &AuthorId,
+    options: &FetchOptions,
+    phase: &Mutex<&'static str>,
+) -> Result<(AuthorInfo, FetchMeta)> {
+    if let Some(cached) = options
+        .cache
+        .as_ref()
+        .and_then(|cache| cache.get(author_id))
+    {
+        return Ok(cached);
+    }
+
+    let (author_info, meta) = scrape_author_info(author_id, options, phase)
+        .instrument(tracing::info_span!(
+            "fetch_author_info",
+            scholar.author_id = %author_id
+        ))
+        .await?;
+    if let Some(cache) = &options.cache {
+        cache.insert(author_id, author_info.clone(), meta);
+    }
+    Ok((author_info, meta))
+}
+
+/// Parses the HTML of a profile page into an [`AuthorInfo`], e.g. a saved page or a mock response
+///
+/// Same as [`parse_author_info`], but first checks the size against
+/// `options.max_html_bytes` as fetching does.
+///
+/// # Arguments
+///
+/// * `html` - The HTML source of the author's page
+/// * `options` - The fetch options (size limit, name extraction and ordering)
+///
+/// # Returns
+///
+/// * `Result<AuthorInfo>` - The extracted information, or [`ScraperError::ResponseTooLarge`]
+pub fn parse_author_page(html: &str, options: &FetchOptions) -> Result<AuthorInfo> {
+    parse_author_info(&parse_html(html, options)?, options)
+}
+
+/// Extracts all information of a profile page into an [`AuthorInfo`]
+///
+/// This is the parsing step of [`fetch_author_info`], without any request.
+/// The publications are left empty, they need requests of their own.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+/// * `options` - The fetch options (name extraction and ordering)
+///
+/// # Returns
+///
+/// * `Result<AuthorInfo>` - The extracted information
+pub fn parse_author_info(document: &Html, options: &FetchOptions) -> Result<AuthorInfo> {
+    let _span = tracing::info_span!("parse_author_info").entered();
+
+    if is_robot_check_page(document) {
+        tracing::warn!("page is a robot check, treating as rate limited");
+        return Err(ScraperError::RateLimited.into());
+    }
+    let name = extract_name(document, options)?;
+    if is_robot_check_name(&name) {
+        tracing::warn!(
+            name,
+            "name looks like a robot check, treating as rate limited"
+        );
+        return Err(ScraperError::RateLimited.into());
+    }
+    let mode = options.parse_mode;
+    let mut warnings = Vec::new();
+    let rows = mode.recover(
+        extract_summary_rows(document),
+        "summary table",
+        &mut warnings,
+    )?;
+    let (total, h_index, i10_index) =
+        mode.recover(metrics_from_rows(&rows), "summary metrics", &mut warnings)?;
+    let yearly_citations = mode.recover(
+        extract_citations(document),
+        "citation histogram",
+        &mut warnings,
+    )?;
+
+    let verified_email_domains = extract_email_domains(document);
+    let verified = !verified_email_domains.is_empty() && !has_disclaimer(document);
+
+    let mut author_info = AuthorInfo {
         name,
         total,
         h_index,
         i10_index,
+        raw_metrics: rows
+            .into_iter()
+            .map(|(label, all, recent)| (label, (all, recent)))
+            .collect(),
+        citation_links: extract_citation_links(document),
         yearly_citations,
+        affiliation: extract_affiliation(document),
+        verified_email_domains,
+        verified,
+        photo_url: extract_photo_url(document),
+        homepage: extract_homepage(document),
+        coauthors: extract_coauthors(document),
+        publications: Vec::new(),
+        publications_truncated: false,
+        extra_metrics: extract_extra_metrics(document),
+        sources: BTreeMap::new(),
+        warnings,
     };
 
-    let res = serde_yaml::to_string(&author_info)?;
-    Ok(res)
+    if options.drop_current_year {
+        author_info = author_info.without_current_year();
+    }
+    if !options.raw_order {
+        author_info.coauthors.sort_by(|a, b| {
+            a.name
+                .cmp(&b.name)
+                .then_with(|| a.author_id.cmp(&b.author_id))
+        });
+    }
+
+    Ok(author_info)
+}
+
+impl TryFrom<&Html> for AuthorInfo {
+    type Error = ScraperError;
+
+    /// Extracts a profile page with the default options, see [`parse_author_info`]
+    fn try_from(document: &Html) -> Result<Self, Self::Error> {
+        parse_author_info(document, &FetchOptions::default()).map_err(ScraperError::from)
+    }
+}
+
+/// Fetches and parses the profile page, returning the size of its body as well
+async fn parse_profile_page(
+    author_id: &AuthorId,
+    options: &FetchOptions,
+) -> Result<(AuthorInfo, usize)> {
+    let (document, body_bytes) = fetch_page(author_id, options).await?;
+    Ok((parse_author_info(&document, options)?, body_bytes))
+}
+
+/// Whether the histogram looks unrendered, being empty despite many citations
+fn has_missing_histogram(author_info: &AuthorInfo) -> bool {
+    author_info.yearly_citations.is_empty() && author_info.total >= EMPTY_HISTOGRAM_MIN_CITATIONS
+}
+
+/// Fetches and extracts an author's profile, bypassing the cache
+async fn scrape_author_info(
+    author_id: &AuthorId,
+    options: &FetchOptions,
+    phase: &Mutex<&'static str>,
+) -> Result<(AuthorInfo, FetchMeta)> {
+    let (mut author_info, mut body_bytes) = parse_profile_page(author_id, options).await?;
+    if options.retry_empty_histogram && has_missing_histogram(&author_info) {
+        tracing::warn!("Empty citation histogram for {author_id}, fetching the page again");
+        sleep(options.politeness_delay).await;
+        (author_info, body_bytes) = parse_profile_page(author_id, options).await?;
+    }
+    let meta = FetchMeta {
+        scraped_at: unix_now().as_secs(),
+        from_cache: false,
+        body_bytes,
+    };
+
+    if options.include_publications {
+        *phase
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = PHASE_PUBLICATIONS;
+        sleep(options.politeness_delay).await;
+        (author_info.publications, author_info.publications_truncated) =
+            fetch_publications_capped(author_id, options).await?;
+    }
+
+    Ok((author_info, meta))
 }
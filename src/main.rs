@@ -1,5 +1,6 @@
 use leptos::*;
 use leptos_scholar::*;
+use std::collections::HashMap;
 
 fn main() {
     mount_to_body(|| view! { <Render /> })
@@ -7,15 +8,41 @@ fn main() {
 
 #[component]
 fn render() -> impl IntoView {
-    let (author_id, set_author_id) = create_signal("H7sOPf8AAAAJ".to_string());
+    let (author_id, set_author_id) = create_signal(EXAMPLE_AUTHOR_ID.to_string());
+
+    // results of earlier fetches, so switching back to an id doesn't hit Scholar again
+    let cache = store_value(HashMap::<String, (AuthorInfo, FetchMeta)>::new());
 
     // our resource
-    let async_data = create_resource(author_id, |author_id| async move {
-        fetch_info(author_id)
-            .await
-            .unwrap_or_else(|e| e.to_string())
+    let async_data = create_resource(author_id, move |author_id| async move {
+        if let Some((info, meta)) = cache.with_value(|cache| cache.get(&author_id).cloned()) {
+            return Ok((
+                info,
+                FetchMeta {
+                    from_cache: true,
+                    ..meta
+                },
+            ));
+        }
+
+        let result = fetch_author_info_with_meta(&author_id, &FetchOptions::default()).await;
+        if let Ok(result) = &result {
+            cache.update_value(|cache| {
+                cache.insert(author_id, result.clone());
+            });
+        }
+        result.map_err(ScraperError::from)
     });
 
+    let (format, set_format) = create_signal(stored_format());
+
+    let refresh = move |_| {
+        cache.update_value(|cache| {
+            cache.remove(&author_id.get_untracked());
+        });
+        async_data.refetch();
+    };
+
     view! {
         <main>
             <h1>Scholar</h1>
@@ -29,14 +56,93 @@ fn render() -> impl IntoView {
             prop:value=author_id
             />
 
-            <pre>
-            <Suspense
-                fallback=move || view! { <p>" Loading "</p> }
-            >
-            {async_data.get()}
+            <label>Format:</label>
+            <select on:change=move |ev| {
+                if let Ok(format) = event_target_value(&ev).parse() {
+                    store_format(format);
+                    set_format(format);
+                }
+            }>
+                {OutputFormat::ALL.into_iter().map(|option| view! {
+                    <option value=option.to_string() selected=move || format() == option>
+                        {option.to_string()}
+                    </option>
+                }).collect_view()}
+            </select>
 
+            <Suspense fallback=move || view! { <ProfileSkeleton /> }>
+            {move || async_data.get().map(|data| match data {
+                Ok((info, meta)) => view! {
+                    <h2>{info.name.clone()}</h2>
+                    <p class="freshness">
+                        {freshness(&meta)}
+                        " "
+                        <button on:click=refresh>"Refresh"</button>
+                        " "
+                        <a href=move || profile_url(&author_id())>"Profile on Google Scholar"</a>
+                    </p>
+                    {move || match format().serialize(&info) {
+                        Ok(text) => view! {
+                            <p>
+                                <a
+                                    download=format!("{}.{}", author_id(), format().extension())
+                                    href=data_url(&text, format().mime_type())
+                                >"Download"</a>
+                            </p>
+                            <pre>{text}</pre>
+                        }.into_view(),
+                        Err(e) => view! { <pre>{e.to_string()}</pre> }.into_view(),
+                    }}
+                }.into_view(),
+                Err(e) => view! { <pre>{e.to_string()}</pre> }.into_view(),
+            })}
             </Suspense>
-            </pre>
         </main>
     }
 }
+
+/// Describes when the displayed result was fetched, e.g. "fetched 5 minutes ago (cached)"
+fn freshness(meta: &FetchMeta) -> String {
+    let age = match meta.age().as_secs() / 60 {
+        0 => "just now".to_string(),
+        1 => "1 minute ago".to_string(),
+        minutes => format!("{minutes} minutes ago"),
+    };
+
+    if meta.from_cache {
+        format!("fetched {age} (cached)")
+    } else {
+        format!("fetched {age}")
+    }
+}
+
+/// Key of the chosen output format in `localStorage`
+const FORMAT_KEY: &str = "leptos-scholar.format";
+
+/// Reads the output format chosen in an earlier visit, YAML if there is none
+fn stored_format() -> OutputFormat {
+    window()
+        .local_storage()
+        .ok()
+        .flatten()
+        .and_then(|storage| storage.get_item(FORMAT_KEY).ok().flatten())
+        .and_then(|format| format.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Remembers the chosen output format for the next visit
+fn store_format(format: OutputFormat) {
+    if let Ok(Some(storage)) = window().local_storage() {
+        let _ = storage.set_item(FORMAT_KEY, &format.to_string());
+    }
+}
+
+/// Encodes a text as `data:` URL, for downloading it
+fn data_url(text: &str, mime_type: &str) -> String {
+    // `byte_serialize` encodes spaces as '+' (and '+' itself as "%2B")
+    let encoded: String = url::form_urlencoded::byte_serialize(text.as_bytes()).collect();
+    format!(
+        "data:{mime_type};charset=utf-8,{}",
+        encoded.replace('+', "%20")
+    )
+}
@@ -0,0 +1,92 @@
+use crate::AuthorInfo;
+
+/// Source recorded in [`AuthorInfo::sources`] for fields filled by [`merge_author_info`]
+const SECONDARY_SOURCE: &str = "secondary";
+
+/// Merges two profiles of the same author, e.g. from Scholar and a fallback source
+///
+/// Every field of `primary` is kept unless it's missing (an empty name, list
+/// or map, `None`, or a metric of 0), in which case it's taken from
+/// `secondary`. Each filled field is recorded in [`AuthorInfo::sources`] by
+/// its serialized name, with the source `secondary` had recorded for it or
+/// `"secondary"`. The merge is deterministic, only the fields are compared.
+///
+/// # Arguments
+///
+/// * `primary` - The preferred profile
+/// * `secondary` - The profile filling the gaps
+///
+/// # Returns
+///
+/// * `AuthorInfo` - The merged profile
+pub fn merge_author_info(primary: &AuthorInfo, secondary: &AuthorInfo) -> AuthorInfo {
+    let mut merged = primary.clone();
+    let mut filled = Vec::new();
+
+    if merged.name.trim().is_empty() && !secondary.name.trim().is_empty() {
+        merged.name = secondary.name.clone();
+        filled.push("name");
+    }
+    for (field, value, fallback) in [
+        ("total", &mut merged.total, secondary.total),
+        ("h_index", &mut merged.h_index, secondary.h_index),
+        ("i10_index", &mut merged.i10_index, secondary.i10_index),
+    ] {
+        if *value == 0 && fallback > 0 {
+            *value = fallback;
+            filled.push(field);
+        }
+    }
+    if merged.raw_metrics.is_empty() && !secondary.raw_metrics.is_empty() {
+        merged.raw_metrics = secondary.raw_metrics.clone();
+        filled.push("raw_metrics");
+    }
+    if merged.citation_links.is_none() && secondary.citation_links.is_some() {
+        merged.citation_links = secondary.citation_links.clone();
+        filled.push("citation_links");
+    }
+    if merged.yearly_citations.is_empty() && !secondary.yearly_citations.is_empty() {
+        merged.yearly_citations = secondary.yearly_citations.clone();
+        filled.push("years");
+    }
+    if merged.affiliation.is_none() && secondary.affiliation.is_some() {
+        merged.affiliation = secondary.affiliation.clone();
+        filled.push("affiliation");
+    }
+    if merged.verified_email_domains.is_empty() && !secondary.verified_email_domains.is_empty() {
+        merged.verified_email_domains = secondary.verified_email_domains.clone();
+        filled.push("verified_email_domains");
+    }
+    if merged.photo_url.is_none() && secondary.photo_url.is_some() {
+        merged.photo_url = secondary.photo_url.clone();
+        filled.push("photo_url");
+    }
+    if merged.homepage.is_none() && secondary.homepage.is_some() {
+        merged.homepage = secondary.homepage.clone();
+        filled.push("homepage");
+    }
+    if merged.coauthors.is_empty() && !secondary.coauthors.is_empty() {
+        merged.coauthors = secondary.coauthors.clone();
+        filled.push("coauthors");
+    }
+    if merged.publications.is_empty() && !secondary.publications.is_empty() {
+        // The truncation flag describes the list, so it comes along
+        merged.publications = secondary.publications.clone();
+        merged.publications_truncated = secondary.publications_truncated;
+        filled.push("publications");
+    }
+    if merged.extra_metrics.is_empty() && !secondary.extra_metrics.is_empty() {
+        merged.extra_metrics = secondary.extra_metrics.clone();
+        filled.push("extra_metrics");
+    }
+
+    for field in filled {
+        let source = secondary
+            .sources
+            .get(field)
+            .cloned()
+            .unwrap_or_else(|| SECONDARY_SOURCE.to_string());
+        merged.sources.insert(field.to_string(), source);
+    }
+    merged
+}
@@ -0,0 +1,52 @@
+/// Computes the h-index: the largest `h` such that `h` papers have at least `h` citations each
+///
+/// # Arguments
+///
+/// * `citations` - The citation count of each paper, in any order
+pub fn compute_h_index(citations: &[usize]) -> usize {
+    let mut sorted = citations.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    sorted
+        .iter()
+        .enumerate()
+        .take_while(|(rank, &count)| count > *rank)
+        .count()
+}
+
+/// Computes the g-index: the largest `g` such that the top `g` papers have at least `g²` citations together
+///
+/// `g` is bounded by the number of papers.
+///
+/// # Arguments
+///
+/// * `citations` - The citation count of each paper, in any order
+pub fn compute_g_index(citations: &[usize]) -> usize {
+    let mut sorted = citations.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut sum = 0;
+    let mut g = 0;
+    for (rank, count) in sorted.iter().enumerate() {
+        sum += count;
+        if sum >= (rank + 1) * (rank + 1) {
+            g = rank + 1;
+        }
+    }
+    g
+}
+
+/// Computes an i-index: the number of papers with at least `threshold` citations
+///
+/// Google Scholar reports the i10-index, i.e. `compute_i_index(citations, 10)`.
+///
+/// # Arguments
+///
+/// * `citations` - The citation count of each paper, in any order
+/// * `threshold` - The minimum number of citations for a paper to count
+pub fn compute_i_index(citations: &[usize], threshold: usize) -> usize {
+    citations
+        .iter()
+        .filter(|&&count| count >= threshold)
+        .count()
+}
@@ -0,0 +1,51 @@
+use crate::AuthorInfo;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Finds the ORCID iD of an author in their scraped profile
+///
+/// The homepage link is checked first, then the text of the extra sidebar
+/// sections, for a link like `https://orcid.org/0000-0002-1825-0097`. No
+/// requests are made, so an ORCID iD only listed on the homepage itself isn't
+/// found. Candidates with a wrong check digit are skipped.
+///
+/// # Arguments
+///
+/// * `info` - The scraped profile
+///
+/// # Returns
+///
+/// * `Option<String>` - The iD like `0000-0002-1825-0097`, `None` if the profile doesn't link one
+pub fn orcid_from_profile(info: &AuthorInfo) -> Option<String> {
+    static ORCID_LINK: OnceLock<Regex> = OnceLock::new();
+    let orcid_link = ORCID_LINK
+        .get_or_init(|| Regex::new(r"(?i)orcid\.org/(\d{4}-\d{4}-\d{4}-\d{3}[\dX])").unwrap());
+
+    let homepage = info.homepage.as_ref().map(|url| url.as_str());
+    homepage
+        .into_iter()
+        .chain(info.extra_metrics.values().map(String::as_str))
+        .flat_map(|text| orcid_link.captures_iter(text))
+        .map(|captures| captures[1].to_uppercase())
+        .find(|orcid| has_valid_check_digit(orcid))
+}
+
+/// Verifies the last character of an ORCID iD (ISO 7064 MOD 11-2)
+fn has_valid_check_digit(orcid: &str) -> bool {
+    let characters: Vec<char> = orcid.chars().filter(|c| *c != '-').collect();
+    let Some((&check, digits)) = characters.split_last() else {
+        return false;
+    };
+
+    let total = digits
+        .iter()
+        .filter_map(|digit| digit.to_digit(10))
+        .fold(0, |total, digit| (total + digit) * 2);
+    let expected = (12 - total % 11) % 11;
+    let expected = if expected == 10 {
+        'X'
+    } else {
+        char::from_digit(expected, 10).unwrap_or('?')
+    };
+    check == expected
+}
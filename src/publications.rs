@@ -0,0 +1,381 @@
+use crate::{
+    element_text, fetch_html, profile_url, query_param, resolve_url, retry::sleep, selectors,
+    to_author_id, AuthorId, FetchOptions, Progress,
+};
+use anyhow::Result;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use url::Url;
+
+/// Number of publications requested per page (the maximum Scholar allows)
+const PAGE_SIZE: usize = 100;
+
+/// Order in which Scholar lists the publications of a profile, see [`FetchOptions::publication_sort`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PublicationSort {
+    /// Most cited first, Scholar's default
+    #[default]
+    Citations,
+    /// Newest first (`sortby=pubdate`)
+    Date,
+}
+
+impl PublicationSort {
+    /// Returns the value of the `sortby` URL parameter, `None` for Scholar's default order
+    pub fn query_value(self) -> Option<&'static str> {
+        match self {
+            PublicationSort::Citations => None,
+            PublicationSort::Date => Some("pubdate"),
+        }
+    }
+}
+
+/// Builds the URL of a page of an author's publication list
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `start` - The index of the first publication on the page
+/// * `sort` - The order of the list
+///
+/// # Returns
+///
+/// * `String` - The URL of the page with up to 100 publications
+pub fn publications_url(author_id: &str, start: usize, sort: PublicationSort) -> String {
+    let mut url = format!(
+        "{}&cstart={start}&pagesize={PAGE_SIZE}",
+        profile_url(author_id)
+    );
+    if let Some(sortby) = sort.query_value() {
+        url.push_str("&sortby=");
+        url.push_str(sortby);
+    }
+    url
+}
+
+/// A publication listed on an author's profile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Publication {
+    /// Title of the publication
+    pub title: String,
+    /// Authors as listed by Scholar (long lists end with "...")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    /// Journal, conference or other venue
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue: Option<String>,
+    /// Year of publication
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub year: Option<usize>,
+    /// Number of citations
+    pub citations: usize,
+    /// Scholar's ID of the publication (`citation_for_view`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cite_id: Option<String>,
+    /// Link to the publication's detail page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<Url>,
+    /// Link to the list of citing works
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cited_by_url: Option<Url>,
+}
+
+/// Fetches all publications of an author
+///
+/// The list is requested page by page, waiting `options.politeness_delay`
+/// between pages, until a page comes back incomplete or
+/// `options.max_publications` is reached. Entries that show up on more than
+/// one page (when the list shifts between requests) are merged by their
+/// [`cite_id`](Publication::cite_id). Distinct works with the same title,
+/// like two editorials, are all kept.
+///
+/// Unless `options.raw_order` is set, the result is sorted with
+/// [`sort_publications`] instead of Scholar's order (most cited first).
+///
+/// Progress is reported after every page. The total is only known once the
+/// last page has arrived.
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `options` - The fetch options
+///
+/// # Returns
+///
+/// * `Result<Vec<Publication>>` - The publications
+pub async fn fetch_publications(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    options: &FetchOptions,
+) -> Result<Vec<Publication>> {
+    let (publications, _) = fetch_publications_capped(&to_author_id(author_id)?, options).await?;
+    Ok(publications)
+}
+
+/// Same as [`fetch_publications`], but also returns whether the list was cut at `options.max_publications`
+pub(crate) async fn fetch_publications_capped(
+    author_id: &AuthorId,
+    options: &FetchOptions,
+) -> Result<(Vec<Publication>, bool)> {
+    let max = options.max_publications.unwrap_or(usize::MAX);
+    let mut publications = Vec::new();
+    let mut more_available = false;
+
+    for page in 0.. {
+        if page > 0 {
+            sleep(options.politeness_delay).await;
+        }
+
+        let url = publications_url(author_id, page * PAGE_SIZE, options.publication_sort);
+        let rows = extract_publications(&fetch_html(&url, options).await?);
+        let complete = rows.len() == PAGE_SIZE;
+        publications = dedupe_page_overlap(publications.into_iter().chain(rows).collect());
+        more_available = complete || publications.len() > max;
+
+        options.report(Progress {
+            fetched: publications.len().min(max),
+            total_estimate: (!complete).then_some(publications.len().min(max)),
+            current_author: author_id.to_string(),
+        });
+
+        if !complete || publications.len() >= max {
+            break;
+        }
+    }
+
+    // The cap keeps the first publications in `options.publication_sort`, by default the most cited
+    let truncated = more_available && publications.len() >= max;
+    publications.truncate(max);
+
+    if !options.raw_order {
+        sort_publications(&mut publications);
+    }
+    Ok((publications, truncated))
+}
+
+/// Fetches the publications of an author from a year on, without crawling the whole list
+///
+/// Uses the default fetch options, see [`publications_since_with_options`].
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `year` - The earliest publication year to include
+///
+/// # Returns
+///
+/// * `Result<Vec<Publication>>` - The publications of `year` and later
+pub async fn publications_since(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    year: usize,
+) -> Result<Vec<Publication>> {
+    publications_since_with_options(author_id, year, &FetchOptions::default()).await
+}
+
+/// Same as [`publications_since`], but with custom fetch options
+///
+/// Scholar lists publications most cited first by default, so the list is
+/// requested in [`PublicationSort::Date`] order instead, whatever
+/// `options.publication_sort` says. Paging
+/// stops at the first page that reaches a publication older than `year` or
+/// comes back incomplete, so a periodic sync usually costs a single request.
+/// Publications without a year can't be placed and are left out. Like
+/// [`fetch_publications`], pages are `options.politeness_delay` apart and the
+/// result is sorted with [`sort_publications`] unless `options.raw_order` is
+/// set.
+pub async fn publications_since_with_options(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    year: usize,
+    options: &FetchOptions,
+) -> Result<Vec<Publication>> {
+    let author_id = to_author_id(author_id)?;
+    let mut publications = Vec::new();
+
+    for page in 0.. {
+        if page > 0 {
+            sleep(options.politeness_delay).await;
+        }
+
+        let url = publications_url(&author_id, page * PAGE_SIZE, PublicationSort::Date);
+        let rows = extract_publications(&fetch_html(&url, options).await?);
+        let complete = rows.len() == PAGE_SIZE;
+        let reached_cutoff = rows
+            .iter()
+            .any(|publication| publication.year.is_some_and(|published| published < year));
+        publications.extend(
+            rows.into_iter()
+                .filter(|publication| publication.year.is_some_and(|published| published >= year)),
+        );
+
+        let done = !complete || reached_cutoff;
+        options.report(Progress {
+            fetched: publications.len(),
+            total_estimate: done.then_some(publications.len()),
+            current_author: author_id.to_string(),
+        });
+        if done {
+            break;
+        }
+    }
+
+    let mut publications = dedupe_page_overlap(publications);
+    if !options.raw_order {
+        sort_publications(&mut publications);
+    }
+    Ok(publications)
+}
+
+/// Sorts publications by year (newest first), then citations (most first), then title
+///
+/// Publications without a year come last. This is the default order of
+/// [`fetch_publications`] and gives reproducible output for snapshots and diffs.
+pub fn sort_publications(publications: &mut [Publication]) {
+    publications.sort_by(|a, b| {
+        b.year
+            .cmp(&a.year)
+            .then_with(|| b.citations.cmp(&a.citations))
+            .then_with(|| a.title.cmp(&b.title))
+    });
+}
+
+/// Extracts the publications listed on a profile page
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the author's page
+///
+/// # Returns
+///
+/// * `Vec<Publication>` - The publications on this page in DOM order
+pub(crate) fn extract_publications(document: &Html) -> Vec<Publication> {
+    let row_selector = Selector::parse(selectors::PUBLICATIONS).unwrap();
+    let title_selector = Selector::parse(selectors::PUBLICATION_TITLE).unwrap();
+    let gray_selector = Selector::parse(selectors::PUBLICATION_DETAILS).unwrap();
+    let citations_selector = Selector::parse(selectors::PUBLICATION_CITATIONS).unwrap();
+    let year_selector = Selector::parse(selectors::PUBLICATION_YEAR).unwrap();
+
+    document
+        .select(&row_selector)
+        .filter_map(|row| {
+            let title = row.select(&title_selector).next()?;
+            let href = title.value().attr("href");
+            let mut gray = row.select(&gray_selector).map(element_text);
+            let citations = row.select(&citations_selector).next();
+            let year = row
+                .select(&year_selector)
+                .next()
+                .and_then(|element| element_text(element).parse().ok());
+
+            let authors = gray
+                .next()
+                .map(|authors| {
+                    authors
+                        .split(", ")
+                        .filter(|author| !author.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or_default();
+            // The venue line repeats the year at the end (", 2020")
+            let venue = gray
+                .next()
+                .map(|venue| match year {
+                    Some(year) => venue.trim_end_matches(&format!(", {year}")).to_string(),
+                    None => venue,
+                })
+                .filter(|venue| !venue.is_empty());
+
+            Some(Publication {
+                title: element_text(title),
+                authors,
+                venue,
+                year,
+                citations: citations
+                    .and_then(|element| element_text(element).parse().ok())
+                    .unwrap_or(0),
+                cite_id: href.and_then(|href| query_param(href, "citation_for_view")),
+                url: href.and_then(resolve_url),
+                cited_by_url: citations
+                    .and_then(|element| element.value().attr("href"))
+                    .and_then(resolve_url),
+            })
+        })
+        .collect()
+}
+
+/// Removes duplicate publications, matching them by normalized title
+///
+/// For merging the lists of several sources, which share no IDs. Titles are
+/// compared in lowercase, without punctuation and with collapsed whitespace,
+/// so distinct works with the same title (e.g. "Editorial") are merged too. Of each group of duplicates the entry with the most citations
+/// is kept (the first one if they are equal), at the position of the group's
+/// first occurrence, so the result is deterministic.
+///
+/// # Arguments
+///
+/// * `pubs` - The publications, possibly containing duplicates
+///
+/// # Returns
+///
+/// * `Vec<Publication>` - The deduplicated publications
+pub fn dedupe_publications(pubs: Vec<Publication>) -> Vec<Publication> {
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut deduped: Vec<Publication> = Vec::new();
+
+    for publication in pubs {
+        match positions.get(&normalize_title(&publication.title)) {
+            Some(&i) => {
+                if publication.citations > deduped[i].citations {
+                    deduped[i] = publication;
+                }
+            }
+            None => {
+                positions.insert(normalize_title(&publication.title), deduped.len());
+                deduped.push(publication);
+            }
+        }
+    }
+
+    deduped
+}
+
+/// Removes entries that showed up on more than one page, matching them by `cite_id`
+///
+/// Of each pair the entry with the most citations is kept, at the position of
+/// the first one. Entries without `cite_id` can't be matched and are all kept.
+fn dedupe_page_overlap(pubs: Vec<Publication>) -> Vec<Publication> {
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    let mut deduped: Vec<Publication> = Vec::new();
+
+    for publication in pubs {
+        let Some(cite_id) = publication.cite_id.clone() else {
+            deduped.push(publication);
+            continue;
+        };
+        match positions.get(&cite_id) {
+            Some(&i) => {
+                if publication.citations > deduped[i].citations {
+                    deduped[i] = publication;
+                }
+            }
+            None => {
+                positions.insert(cite_id, deduped.len());
+                deduped.push(publication);
+            }
+        }
+    }
+
+    deduped
+}
+
+/// Normalizes a title for duplicate detection
+fn normalize_title(title: &str) -> String {
+    title
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,69 @@
+use crate::retry::{sleep, unix_now};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Spaces out requests, shared by all clones
+///
+/// Every request waits for the next free slot, and slots are `interval`
+/// apart. Clones share their slots, so any number of tasks holding clones of
+/// one limiter (e.g. of one [`ScholarClient`](crate::ScholarClient)) together
+/// send at most one request per `interval`.
+#[derive(Clone)]
+pub struct RateLimiter {
+    interval: Duration,
+    /// Time since the Unix epoch when the next request may be sent
+    next_slot: Arc<Mutex<Duration>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing one request per `interval`, the first one right away
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_slot: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Returns the minimum time between two requests
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Waits until the next free slot and takes it
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut next_slot = self
+                .next_slot
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            let now = unix_now();
+            let slot = (*next_slot).max(now);
+            *next_slot = slot + self.interval;
+            slot - now
+        };
+
+        if !wait.is_zero() {
+            sleep(wait).await;
+        }
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Paces the request number `index` of a batch with `concurrency` requests in flight
+///
+/// The first requests fill the slots right away, later ones reuse a slot that
+/// just finished and wait `delay` first. So each slot sends at most one
+/// request per `delay`.
+pub(crate) async fn stagger(index: usize, concurrency: usize, delay: Duration) {
+    if index >= concurrency {
+        sleep(delay).await;
+    }
+}
@@ -0,0 +1,122 @@
+use crate::AuthorInfo;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Number of publications in the second table of [`to_markdown_table`]
+const MARKDOWN_TOP_PUBLICATIONS: usize = 10;
+
+/// Block characters of the sparkline, from lowest to highest
+const SPARK_BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Renders yearly citations as a one-line text sparkline with the year range beneath
+///
+/// Each year is one block character, scaled to the year with the most
+/// citations. Longer ranges print the years below both ends of the bars. E.g.
+///
+/// ```text
+/// ▁▂▄▅▇█
+/// 2019-2024
+/// ```
+///
+/// # Arguments
+///
+/// * `yearly` - The citation count per year
+///
+/// # Returns
+///
+/// * `String` - The two-line sparkline, empty if there are no years
+pub fn render_sparkline(yearly: &BTreeMap<usize, usize>) -> String {
+    let (Some(first), Some(last)) = (yearly.keys().next(), yearly.keys().next_back()) else {
+        return String::new();
+    };
+    let max = yearly.values().copied().max().unwrap_or(0).max(1);
+    let top = SPARK_BLOCKS.len() - 1;
+
+    let bars: String = yearly
+        .values()
+        .map(|&count| SPARK_BLOCKS[(count * top + max / 2) / max])
+        .collect();
+
+    let width = yearly.len();
+    let range = if first == last {
+        first.to_string()
+    } else if width > 8 {
+        // Years at both ends of the bars
+        format!("{first:<pad$}{last}", pad = width - 4)
+    } else {
+        format!("{first}-{last}")
+    };
+
+    format!("{bars}\n{range}")
+}
+
+/// Formats the headline metrics of an author as a GitHub-flavored Markdown table
+///
+/// The table has the all-time and, where Scholar shows it, the recent value
+/// ("Since <year>") of the citations, h-index and i10-index. If the
+/// publications were fetched, a second table lists the most cited ones. E.g.
+///
+/// ```text
+/// | Metric | All | Recent |
+/// | --- | ---: | ---: |
+/// | Citations | 1234 | 567 |
+/// ```
+///
+/// Pipes in names and titles are escaped and line breaks replaced, so the
+/// tables stay intact.
+///
+/// # Arguments
+///
+/// * `info` - The author
+///
+/// # Returns
+///
+/// * `String` - The Markdown, headed by the author's name
+pub fn to_markdown_table(info: &AuthorInfo) -> String {
+    let mut markdown = format!("**{}**\n\n", markdown_escape(&info.name));
+    markdown.push_str("| Metric | All | Recent |\n| --- | ---: | ---: |\n");
+
+    for (label, all) in [
+        ("Citations", info.total),
+        ("h-index", info.h_index),
+        ("i10-index", info.i10_index),
+    ] {
+        let recent = info
+            .raw_metrics
+            .get(label)
+            .and_then(|(_, recent)| *recent)
+            .map(|recent| recent.to_string())
+            .unwrap_or_default();
+        // Writing to a String can't fail
+        let _ = writeln!(markdown, "| {label} | {all} | {recent} |");
+    }
+
+    if !info.publications.is_empty() {
+        let mut publications: Vec<_> = info.publications.iter().collect();
+        publications.sort_by_key(|publication| std::cmp::Reverse(publication.citations));
+
+        markdown.push_str("\n| Publication | Year | Cited by |\n| --- | ---: | ---: |\n");
+        for publication in publications.into_iter().take(MARKDOWN_TOP_PUBLICATIONS) {
+            let year = publication
+                .year
+                .map(|year| year.to_string())
+                .unwrap_or_default();
+            let _ = writeln!(
+                markdown,
+                "| {} | {year} | {} |",
+                markdown_escape(&publication.title),
+                publication.citations
+            );
+        }
+    }
+
+    markdown
+}
+
+/// Escapes text for a Markdown table cell
+fn markdown_escape(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|")
+}
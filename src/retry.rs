@@ -0,0 +1,240 @@
+use crate::{NetworkErrorKind, ScraperError};
+use anyhow::Result;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::future::Future;
+use std::time::{Duration, UNIX_EPOCH};
+
+/// Controls how throttled requests are retried
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the initial request
+    pub max_retries: usize,
+    /// Wait before the first retry, doubled for every further retry
+    pub base_delay: Duration,
+    /// Upper bound for a single wait, including one requested via `Retry-After`
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Computes the wait before retry number `attempt` (starting at 0)
+    ///
+    /// A wait requested by the server (via `Retry-After`) takes precedence
+    /// over the exponential backoff. Either way the wait is capped at `max_delay`.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The number of the retry, starting at 0
+    /// * `requested` - The wait requested by the server, e.g. from [`retry_after`]
+    ///
+    /// # Returns
+    ///
+    /// * `Duration` - The wait before the retry
+    pub fn delay(&self, attempt: usize, requested: Option<Duration>) -> Duration {
+        let delay = requested.unwrap_or_else(|| {
+            let factor = 1u32.checked_shl(attempt as u32).unwrap_or(u32::MAX);
+            self.base_delay.saturating_mul(factor)
+        });
+        delay.min(self.max_delay)
+    }
+}
+
+/// A throttled attempt, with the wait the server asked for
+///
+/// Returned by an attempt passed to [`with_retry`] to honor `Retry-After`.
+/// If no retries are left, `error` is returned instead.
+#[derive(Debug, thiserror::Error)]
+#[error("{error}")]
+pub(crate) struct Throttled {
+    pub(crate) error: ScraperError,
+    pub(crate) retry_after: Option<Duration>,
+}
+
+impl Throttled {
+    /// Wraps an error with the `Retry-After` of the response that caused it
+    pub(crate) fn new(error: ScraperError, headers: &HeaderMap) -> Self {
+        Self {
+            error,
+            retry_after: retry_after(headers),
+        }
+    }
+}
+
+/// Runs an operation, retrying it with backoff while it is throttled
+///
+/// An attempt counts as throttled if it fails with
+/// [`ScraperError::RateLimited`], [`ScraperError::ServiceUnavailable`] or an
+/// HTTP status error 429 or 503 of `reqwest` (e.g. from `error_for_status`).
+/// Other `reqwest` errors are classified into [`ScraperError::Network`] where
+/// possible, and retried if their kind
+/// [is transient](NetworkErrorKind::is_transient). Other errors are returned
+/// right away. This backs the Scholar requests and
+/// can wrap calls to other services with a policy suiting their limits.
+///
+/// # Arguments
+///
+/// * `policy` - How often and how long to wait between attempts
+/// * `attempt` - Runs one attempt, given its number (starting at 0)
+///
+/// # Returns
+///
+/// * `Result<T>` - The result of the first attempt that isn't throttled, or the error of the last one
+pub async fn with_retry<F, Fut, T>(policy: &RetryPolicy, mut attempt: F) -> Result<T>
+where
+    F: FnMut(usize) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut number = 0;
+    loop {
+        let error = match attempt(number).await {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        let (error, requested) = match error.downcast::<Throttled>() {
+            Ok(throttled) => (throttled.error.into(), throttled.retry_after),
+            Err(error) => (classify_network_error(error), None),
+        };
+
+        if number >= policy.max_retries || !is_retryable(&error) {
+            return Err(error);
+        }
+        sleep(policy.delay(number, requested)).await;
+        number += 1;
+    }
+}
+
+/// Checks whether an error means the service asked to slow down or the network hiccuped
+fn is_retryable(error: &anyhow::Error) -> bool {
+    if let Some(error) = error.downcast_ref::<ScraperError>() {
+        return match error {
+            ScraperError::RateLimited | ScraperError::ServiceUnavailable => true,
+            ScraperError::Network(kind, _) => kind.is_transient(),
+            _ => false,
+        };
+    }
+    error
+        .downcast_ref::<reqwest::Error>()
+        .and_then(reqwest::Error::status)
+        .is_some_and(|status| {
+            status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+        })
+}
+
+/// Turns a `reqwest` error without a status into a [`ScraperError::Network`] of its kind
+///
+/// Other errors, and network errors of no known kind (e.g. a refused
+/// connection), are returned unchanged.
+fn classify_network_error(error: anyhow::Error) -> anyhow::Error {
+    let Some(kind) = error
+        .downcast_ref::<reqwest::Error>()
+        .filter(|error| error.status().is_none())
+        .and_then(network_error_kind)
+    else {
+        return error;
+    };
+
+    // The message of `reqwest` only names the URL, the cause is in the sources
+    let message = error
+        .chain()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(": ");
+    ScraperError::Network(kind, message).into()
+}
+
+/// Finds the kind of a network error from the error and its sources
+fn network_error_kind(error: &reqwest::Error) -> Option<NetworkErrorKind> {
+    if error.is_timeout() {
+        return Some(NetworkErrorKind::Timeout);
+    }
+
+    // The message of the error itself contains the URL, which may contain any word
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        if let Some(io_error) = cause.downcast_ref::<std::io::Error>() {
+            match io_error.kind() {
+                std::io::ErrorKind::TimedOut => return Some(NetworkErrorKind::Timeout),
+                std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::UnexpectedEof => {
+                    return Some(NetworkErrorKind::ConnectionReset)
+                }
+                _ => {}
+            }
+        }
+
+        // The resolver and TLS errors have no common type across backends, only their messages
+        let message = cause.to_string().to_lowercase();
+        if message.contains("dns error") || message.contains("failed to lookup address") {
+            return Some(NetworkErrorKind::Dns);
+        }
+        if message.contains("certificate") || message.contains("tls") || message.contains("ssl") {
+            return Some(NetworkErrorKind::Tls);
+        }
+        if message.contains("connection reset") || message.contains("connection closed") {
+            return Some(NetworkErrorKind::ConnectionReset);
+        }
+        source = cause.source();
+    }
+    None
+}
+
+/// Parses the `Retry-After` header, given either as delta-seconds or as an HTTP-date
+///
+/// A date in the past is a wait of zero.
+///
+/// # Arguments
+///
+/// * `headers` - The headers of a response
+///
+/// # Returns
+///
+/// * `Option<Duration>` - The wait the server asked for, `None` if the header is missing or malformed
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()?;
+    Some(date.saturating_sub(unix_now()))
+}
+
+/// Returns the current time as a duration since the Unix epoch
+///
+/// `SystemTime::now` panics on `wasm32-unknown-unknown`, so the browser clock is used there.
+pub(crate) fn unix_now() -> Duration {
+    #[cfg(target_arch = "wasm32")]
+    {
+        Duration::from_millis(js_sys::Date::now() as u64)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::time::SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// Waits for the given duration without blocking the executor
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+}
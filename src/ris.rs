@@ -0,0 +1,77 @@
+use crate::Publication;
+use std::fmt::Write;
+
+/// Words in a venue that mark a conference or workshop paper
+const CONFERENCE_WORDS: [&str; 5] = [
+    "conference",
+    "proceedings",
+    "workshop",
+    "symposium",
+    "congress",
+];
+
+/// Exports publications as RIS records for reference managers like EndNote or Zotero
+///
+/// Every publication becomes a record of `TY`, `TI`, one `AU` line per
+/// author, `PY`, the venue and `ER`. Scholar's publication list doesn't say
+/// what kind of work an entry is, so the type is guessed from the venue: a
+/// venue naming a conference, proceedings or workshop gives `CONF` (venue in
+/// `T2`), any other venue `JOUR` (venue in `JO`) and no venue `GEN`. The
+/// trailing "..." of long author lists is left out.
+///
+/// # Arguments
+///
+/// * `publications` - The publications to export
+///
+/// # Returns
+///
+/// * `String` - The RIS records, empty if there are no publications
+pub fn to_ris(publications: &[Publication]) -> String {
+    let mut ris = String::new();
+
+    for publication in publications {
+        let venue = publication
+            .venue
+            .as_deref()
+            .map(str::trim)
+            .filter(|venue| !venue.is_empty());
+        let kind = match venue {
+            Some(venue) if is_conference(venue) => "CONF",
+            Some(_) => "JOUR",
+            None => "GEN",
+        };
+
+        push_field(&mut ris, "TY", kind);
+        push_field(&mut ris, "TI", &publication.title);
+        for author in &publication.authors {
+            if author.trim() != "..." {
+                push_field(&mut ris, "AU", author);
+            }
+        }
+        if let Some(year) = publication.year {
+            push_field(&mut ris, "PY", &year.to_string());
+        }
+        if let Some(venue) = venue {
+            push_field(&mut ris, if kind == "CONF" { "T2" } else { "JO" }, venue);
+        }
+        if let Some(url) = &publication.url {
+            push_field(&mut ris, "UR", url.as_str());
+        }
+        ris.push_str("ER  - \n");
+    }
+
+    ris
+}
+
+/// Appends a line like `TI  - Title`, with line breaks in the value replaced by spaces
+fn push_field(ris: &mut String, tag: &str, value: &str) {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    // Writing to a String can't fail
+    let _ = writeln!(ris, "{tag}  - {value}");
+}
+
+/// Whether a venue looks like a conference rather than a journal
+fn is_conference(venue: &str) -> bool {
+    let venue = venue.to_lowercase();
+    CONFERENCE_WORDS.iter().any(|word| venue.contains(word))
+}
@@ -0,0 +1,217 @@
+use crate::{
+    element_text, fetch_html, parse_email_domains, query_param, retry::sleep, selectors,
+    FetchOptions, SCHOLAR_LANGUAGE, SCHOLAR_URL,
+};
+use anyhow::Result;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+/// Maximum number of result pages followed by [`search_all_authors`]
+const MAX_SEARCH_PAGES: usize = 10;
+
+/// A candidate profile returned by the Google Scholar author search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorHit {
+    /// Google Scholar ID of the author
+    pub author_id: String,
+    /// Author name
+    pub name: String,
+    /// Affiliation shown on the result card
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub affiliation: Option<String>,
+    /// Domain of the verified email address
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_domain: Option<String>,
+    /// Research interests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interests: Vec<String>,
+}
+
+/// A page of results of the Google Scholar author search
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchPage {
+    /// The candidates on this page, in the order Scholar ranks them
+    pub hits: Vec<AuthorHit>,
+    /// Cursor of the next page for [`search_authors_page`], `None` on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+/// Searches Google Scholar profiles by author name
+///
+/// Only the first result page is fetched. Pass its `next_cursor` to
+/// [`search_authors_page`] to load more, or use [`search_all_authors`] to
+/// collect all pages. An empty page means no profile matched the name.
+///
+/// # Arguments
+///
+/// * `name` - The (partial) name of the author
+///
+/// # Returns
+///
+/// * `Result<SearchPage>` - The first page of matching profiles
+pub async fn search_authors(name: &str) -> Result<SearchPage> {
+    search_authors_with_options(name, &FetchOptions::default()).await
+}
+
+/// Same as [`search_authors`], but with custom fetch options
+pub async fn search_authors_with_options(name: &str, options: &FetchOptions) -> Result<SearchPage> {
+    search_authors_page_with_options(name, None, options).await
+}
+
+/// Fetches a page of the author search
+///
+/// # Arguments
+///
+/// * `name` - The (partial) name of the author, the same as for the first page
+/// * `cursor` - The `next_cursor` of the previous page, `None` for the first page
+///
+/// # Returns
+///
+/// * `Result<SearchPage>` - The matching profiles on this page
+pub async fn search_authors_page(name: &str, cursor: Option<&str>) -> Result<SearchPage> {
+    search_authors_page_with_options(name, cursor, &FetchOptions::default()).await
+}
+
+/// Same as [`search_authors_page`], but with custom fetch options
+pub async fn search_authors_page_with_options(
+    name: &str,
+    cursor: Option<&str>,
+    options: &FetchOptions,
+) -> Result<SearchPage> {
+    let document = fetch_html(&search_url(name, cursor), options).await?;
+    Ok(SearchPage {
+        hits: extract_author_hits(&document),
+        next_cursor: extract_next_cursor(&document),
+    })
+}
+
+/// Searches Google Scholar profiles by author name and collects all result pages
+///
+/// Pages are followed until the last one (at most 10 pages), waiting
+/// `options.politeness_delay` between them.
+pub async fn search_all_authors(name: &str, options: &FetchOptions) -> Result<Vec<AuthorHit>> {
+    let mut hits = Vec::new();
+    let mut cursor = None;
+
+    for page in 0..MAX_SEARCH_PAGES {
+        if page > 0 {
+            sleep(options.politeness_delay).await;
+        }
+
+        let result = search_authors_page_with_options(name, cursor.as_deref(), options).await?;
+        hits.extend(result.hits);
+
+        cursor = result.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    Ok(hits)
+}
+
+/// Builds the URL of a search result page
+///
+/// # Arguments
+///
+/// * `name` - The searched author name
+/// * `cursor` - The pagination query of a subsequent page, `None` for the first page
+pub(crate) fn search_url(name: &str, cursor: Option<&str>) -> String {
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("view_op", "search_authors")
+        .append_pair("hl", SCHOLAR_LANGUAGE)
+        .append_pair("mauthors", name)
+        .finish();
+
+    match cursor {
+        Some(cursor) => format!("{SCHOLAR_URL}/citations?{query}&{cursor}"),
+        None => format!("{SCHOLAR_URL}/citations?{query}"),
+    }
+}
+
+/// Extracts the author cards from a search result page
+///
+/// Cards without a link to a profile are skipped.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the search page
+///
+/// # Returns
+///
+/// * `Vec<AuthorHit>` - The candidates on this page
+fn extract_author_hits(document: &Html) -> Vec<AuthorHit> {
+    let card_selector = Selector::parse(selectors::SEARCH_RESULT).unwrap();
+    let name_selector = Selector::parse(selectors::SEARCH_NAME).unwrap();
+    let affiliation_selector = Selector::parse(selectors::SEARCH_AFFILIATION).unwrap();
+    let email_selector = Selector::parse(selectors::SEARCH_EMAIL).unwrap();
+    let interest_selector = Selector::parse(selectors::SEARCH_INTERESTS).unwrap();
+
+    document
+        .select(&card_selector)
+        .filter_map(|card| {
+            let link = card.select(&name_selector).next()?;
+            let author_id = query_param(link.value().attr("href")?, "user")?;
+
+            let affiliation = card
+                .select(&affiliation_selector)
+                .next()
+                .map(element_text)
+                .filter(|text| !text.is_empty());
+            let email_domain = card.select(&email_selector).next().and_then(|element| {
+                parse_email_domains(&element_text(element))
+                    .into_iter()
+                    .next()
+            });
+            let interests = card.select(&interest_selector).map(element_text).collect();
+
+            Some(AuthorHit {
+                author_id,
+                name: element_text(link),
+                affiliation,
+                email_domain,
+                interests,
+            })
+        })
+        .collect()
+}
+
+/// Extracts the pagination query of the next result page
+///
+/// The "next" button encodes the target in its `onclick` handler, e.g.
+/// `window.location='/citations?view_op\x3dsearch_authors\x26...\x26after_author\x3dXYZ\x26astart\x3d10'`.
+/// On the last page the button is disabled.
+///
+/// # Arguments
+///
+/// * `document` - The parsed HTML document of the search page
+///
+/// # Returns
+///
+/// * `Option<String>` - The `after_author` and `astart` query, `None` on the last page
+fn extract_next_cursor(document: &Html) -> Option<String> {
+    let next_selector = Selector::parse(selectors::SEARCH_NEXT).unwrap();
+
+    let button = document.select(&next_selector).next()?;
+    if button.value().attr("disabled").is_some() {
+        return None;
+    }
+
+    let target = button
+        .value()
+        .attr("onclick")?
+        .replace("\\x3d", "=")
+        .replace("\\x26", "&");
+    let target = target.split('\'').nth(1)?;
+    let after_author = query_param(target, "after_author")?;
+    let astart = query_param(target, "astart")?;
+
+    Some(
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("after_author", &after_author)
+            .append_pair("astart", &astart)
+            .finish(),
+    )
+}
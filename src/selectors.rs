@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+
+/// What a CSS selector of the crate extracts, see [`known_selectors`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SelectorRole {
+    /// The regular page body, missing on interstitials
+    PageBody,
+    /// The form of Google's cookie consent dialog
+    ConsentForm,
+    /// The CAPTCHA of Google's robot check
+    RobotCheck,
+    /// The author's name
+    Name,
+    /// The table of citations, h-index and i10-index
+    SummaryTable,
+    /// A container of the histogram's year labels (one per layout)
+    HistogramYearsContainer,
+    /// A container of the histogram's bars (one per layout)
+    HistogramBarsContainer,
+    /// The year labels of the histogram
+    HistogramYears,
+    /// The bars of the histogram
+    HistogramBars,
+    /// The citation count inside a bar
+    HistogramCounts,
+    /// The rows of the summary metrics in the mobile layout
+    MobileSummaryRows,
+    /// The label of a mobile summary row
+    MobileSummaryLabel,
+    /// The values of a mobile summary row (all-time and recent)
+    MobileSummaryValues,
+    /// The bars of the histogram in the mobile layout
+    MobileHistogramBars,
+    /// The year inside a mobile histogram bar
+    MobileHistogramYear,
+    /// The citation count inside a mobile histogram bar
+    MobileHistogramCount,
+    /// The affiliation line below the name
+    Affiliation,
+    /// The line with the verified email domains
+    EmailDomains,
+    /// The banner warning that a profile may not belong to the person it names
+    Disclaimer,
+    /// The profile photo
+    Photo,
+    /// The links of the profile header, one of them the homepage
+    Homepage,
+    /// Other labeled sections of the sidebar
+    SidebarSections,
+    /// The co-author entries
+    Coauthors,
+    /// The affiliation of a co-author
+    CoauthorAffiliation,
+    /// The thumbnail of a co-author, within the card around the entry
+    CoauthorPhoto,
+    /// The rows of the publication list
+    Publications,
+    /// The title link of a publication
+    PublicationTitle,
+    /// The authors and venue lines of a publication
+    PublicationDetails,
+    /// The citation count of a publication
+    PublicationCitations,
+    /// The year of a publication
+    PublicationYear,
+    /// The title on a publication's detail page
+    PublicationDetailTitle,
+    /// The rows of fields on a publication's detail page
+    PublicationDetailFields,
+    /// The label of a field on a publication's detail page
+    PublicationDetailLabel,
+    /// The value of a field on a publication's detail page
+    PublicationDetailValue,
+    /// A result card of the author search
+    SearchResult,
+    /// The profile link of a search result
+    SearchName,
+    /// The affiliation of a search result
+    SearchAffiliation,
+    /// The email line of a search result
+    SearchEmail,
+    /// The research interests of a search result
+    SearchInterests,
+    /// The button to the next result page
+    SearchNext,
+}
+
+pub(crate) const PAGE_BODY: &str = "#gs_bdy";
+pub(crate) const CONSENT_FORM: &str = "form[action*=\"consent.google\"]";
+pub(crate) const ROBOT_CHECK: &str = "form#captcha-form, div.g-recaptcha";
+pub(crate) const NAME: &str = "div#gsc_prf_in";
+pub(crate) const SUMMARY_TABLE: &str = "table#gsc_rsb_st";
+pub(crate) const HISTOGRAM_YEARS: &str = "span.gsc_g_t";
+pub(crate) const HISTOGRAM_BARS: &str = "a.gsc_g_a";
+pub(crate) const HISTOGRAM_COUNTS: &str = "span.gsc_g_al";
+pub(crate) const MOBILE_SUMMARY_ROWS: &str = "#gsc_rsb_m div.gsc_rsb_m_r";
+pub(crate) const MOBILE_SUMMARY_LABEL: &str = "span.gsc_rsb_m_l";
+pub(crate) const MOBILE_SUMMARY_VALUES: &str = "span.gsc_rsb_m_v";
+pub(crate) const MOBILE_HISTOGRAM_BARS: &str = "#gsc_rsb_mh div.gsc_rsb_mh_b";
+pub(crate) const MOBILE_HISTOGRAM_YEAR: &str = "span.gsc_rsb_mh_y";
+pub(crate) const MOBILE_HISTOGRAM_COUNT: &str = "span.gsc_rsb_mh_c";
+pub(crate) const AFFILIATION: &str =
+    "div#gsc_prf_i div.gsc_prf_il:not(#gsc_prf_ivh):not(#gsc_prf_int)";
+pub(crate) const EMAIL_DOMAINS: &str = "div#gsc_prf_ivh";
+pub(crate) const DISCLAIMER: &str = "#gsc_prf .gs_alrt";
+pub(crate) const PHOTO: &str = "img#gsc_prf_pup-img";
+pub(crate) const HOMEPAGE: &str = "div#gsc_prf_ivh a";
+pub(crate) const SIDEBAR_SECTIONS: &str = "#gsc_rsb > div:not(#gsc_rsb_cit):not(#gsc_rsb_co)";
+pub(crate) const COAUTHORS: &str = "#gsc_rsb_co div.gsc_rsb_a_desc";
+pub(crate) const COAUTHOR_AFFILIATION: &str = "span.gsc_rsb_a_ext:not(.gsc_rsb_a_ext2)";
+pub(crate) const COAUTHOR_PHOTO: &str = "span.gsc_rsb_ai img";
+pub(crate) const PUBLICATIONS: &str = "#gsc_a_b tr.gsc_a_tr";
+pub(crate) const PUBLICATION_TITLE: &str = "a.gsc_a_at";
+pub(crate) const PUBLICATION_DETAILS: &str = "td.gsc_a_t div.gs_gray";
+pub(crate) const PUBLICATION_CITATIONS: &str = "td.gsc_a_c a.gsc_a_ac";
+pub(crate) const PUBLICATION_YEAR: &str = "td.gsc_a_y span";
+pub(crate) const PUBLICATION_DETAIL_TITLE: &str = "#gsc_oci_title";
+pub(crate) const PUBLICATION_DETAIL_FIELDS: &str = "#gsc_oci_table div.gs_scl";
+pub(crate) const PUBLICATION_DETAIL_LABEL: &str = "div.gsc_oci_field";
+pub(crate) const PUBLICATION_DETAIL_VALUE: &str = "div.gsc_oci_value";
+pub(crate) const SEARCH_RESULT: &str = "div.gsc_1usr";
+pub(crate) const SEARCH_NAME: &str = "h3.gs_ai_name > a";
+pub(crate) const SEARCH_AFFILIATION: &str = "div.gs_ai_aff";
+pub(crate) const SEARCH_EMAIL: &str = "div.gs_ai_eml";
+pub(crate) const SEARCH_INTERESTS: &str = "div.gs_ai_int a.gs_ai_one_int";
+pub(crate) const SEARCH_NEXT: &str = "button.gs_btnPR";
+
+/// Containers of the histogram's year labels and bars, in the order they are tried
+///
+/// The profile page wraps the histogram in `gsc_md_hist_w`, the embeddable
+/// widget and the citations dialog use `gsc_md_hist_b` without it, and the
+/// older layout splits the years (`gsc_g_x`) from the bars (`gsc_g_bars`).
+pub(crate) const HISTOGRAM_LAYOUTS: [(&str, &str); 3] = [
+    (
+        "div.gsc_md_hist_w > div.gsc_md_hist_b",
+        "div.gsc_md_hist_w > div.gsc_md_hist_b",
+    ),
+    ("div.gsc_md_hist_b", "div.gsc_md_hist_b"),
+    ("#gsc_g_x", "#gsc_g_bars"),
+];
+
+/// All selectors in the order of [`known_selectors`]
+const KNOWN_SELECTORS: [(SelectorRole, &str); 44] = [
+    (SelectorRole::PageBody, PAGE_BODY),
+    (SelectorRole::ConsentForm, CONSENT_FORM),
+    (SelectorRole::RobotCheck, ROBOT_CHECK),
+    (SelectorRole::Name, NAME),
+    (SelectorRole::SummaryTable, SUMMARY_TABLE),
+    (
+        SelectorRole::HistogramYearsContainer,
+        HISTOGRAM_LAYOUTS[0].0,
+    ),
+    (SelectorRole::HistogramBarsContainer, HISTOGRAM_LAYOUTS[0].1),
+    (
+        SelectorRole::HistogramYearsContainer,
+        HISTOGRAM_LAYOUTS[1].0,
+    ),
+    (SelectorRole::HistogramBarsContainer, HISTOGRAM_LAYOUTS[1].1),
+    (
+        SelectorRole::HistogramYearsContainer,
+        HISTOGRAM_LAYOUTS[2].0,
+    ),
+    (SelectorRole::HistogramBarsContainer, HISTOGRAM_LAYOUTS[2].1),
+    (SelectorRole::HistogramYears, HISTOGRAM_YEARS),
+    (SelectorRole::HistogramBars, HISTOGRAM_BARS),
+    (SelectorRole::HistogramCounts, HISTOGRAM_COUNTS),
+    (SelectorRole::MobileSummaryRows, MOBILE_SUMMARY_ROWS),
+    (SelectorRole::MobileSummaryLabel, MOBILE_SUMMARY_LABEL),
+    (SelectorRole::MobileSummaryValues, MOBILE_SUMMARY_VALUES),
+    (SelectorRole::MobileHistogramBars, MOBILE_HISTOGRAM_BARS),
+    (SelectorRole::MobileHistogramYear, MOBILE_HISTOGRAM_YEAR),
+    (SelectorRole::MobileHistogramCount, MOBILE_HISTOGRAM_COUNT),
+    (SelectorRole::Affiliation, AFFILIATION),
+    (SelectorRole::EmailDomains, EMAIL_DOMAINS),
+    (SelectorRole::Disclaimer, DISCLAIMER),
+    (SelectorRole::Photo, PHOTO),
+    (SelectorRole::Homepage, HOMEPAGE),
+    (SelectorRole::SidebarSections, SIDEBAR_SECTIONS),
+    (SelectorRole::Coauthors, COAUTHORS),
+    (SelectorRole::CoauthorAffiliation, COAUTHOR_AFFILIATION),
+    (SelectorRole::CoauthorPhoto, COAUTHOR_PHOTO),
+    (SelectorRole::Publications, PUBLICATIONS),
+    (SelectorRole::PublicationTitle, PUBLICATION_TITLE),
+    (SelectorRole::PublicationDetails, PUBLICATION_DETAILS),
+    (SelectorRole::PublicationCitations, PUBLICATION_CITATIONS),
+    (SelectorRole::PublicationYear, PUBLICATION_YEAR),
+    (
+        SelectorRole::PublicationDetailTitle,
+        PUBLICATION_DETAIL_TITLE,
+    ),
+    (
+        SelectorRole::PublicationDetailFields,
+        PUBLICATION_DETAIL_FIELDS,
+    ),
+    (
+        SelectorRole::PublicationDetailLabel,
+        PUBLICATION_DETAIL_LABEL,
+    ),
+    (
+        SelectorRole::PublicationDetailValue,
+        PUBLICATION_DETAIL_VALUE,
+    ),
+    (SelectorRole::SearchResult, SEARCH_RESULT),
+    (SelectorRole::SearchName, SEARCH_NAME),
+    (SelectorRole::SearchAffiliation, SEARCH_AFFILIATION),
+    (SelectorRole::SearchEmail, SEARCH_EMAIL),
+    (SelectorRole::SearchInterests, SEARCH_INTERESTS),
+    (SelectorRole::SearchNext, SEARCH_NEXT),
+];
+
+/// Lists the CSS selectors the crate scrapes with and what each one extracts
+///
+/// Selectors of child elements (e.g. [`SelectorRole::HistogramBars`]) are
+/// applied within their container. The name selector can be overridden with
+/// [`FetchOptions::name_selector`](crate::FetchOptions::name_selector).
+///
+/// # Returns
+///
+/// * `&'static [(SelectorRole, &'static str)]` - The selectors with their roles
+pub fn known_selectors() -> &'static [(SelectorRole, &'static str)] {
+    &KNOWN_SELECTORS
+}
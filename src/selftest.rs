@@ -0,0 +1,175 @@
+use crate::{
+    fetch_html, profile_url, publications::extract_publications, retry::sleep, search::search_url,
+    selectors::known_selectors, FetchOptions, SelectorRole, EXAMPLE_AUTHOR_ID,
+};
+use anyhow::Result;
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Name searched for the search result selectors, common enough to have a next page
+const SELFTEST_SEARCH: &str = "smith";
+
+/// Roles expected on the profile page of [`EXAMPLE_AUTHOR_ID`]
+const PROFILE_ROLES: [SelectorRole; 15] = [
+    SelectorRole::PageBody,
+    SelectorRole::Name,
+    SelectorRole::SummaryTable,
+    SelectorRole::HistogramYearsContainer,
+    SelectorRole::HistogramBarsContainer,
+    SelectorRole::HistogramYears,
+    SelectorRole::HistogramBars,
+    SelectorRole::HistogramCounts,
+    SelectorRole::EmailDomains,
+    SelectorRole::Photo,
+    SelectorRole::Publications,
+    SelectorRole::PublicationTitle,
+    SelectorRole::PublicationDetails,
+    SelectorRole::PublicationCitations,
+    SelectorRole::PublicationYear,
+];
+
+/// Roles expected on the detail page of a publication
+const DETAIL_ROLES: [SelectorRole; 4] = [
+    SelectorRole::PublicationDetailTitle,
+    SelectorRole::PublicationDetailFields,
+    SelectorRole::PublicationDetailLabel,
+    SelectorRole::PublicationDetailValue,
+];
+
+/// Roles expected on the result page of [`SELFTEST_SEARCH`]
+const SEARCH_ROLES: [SelectorRole; 6] = [
+    SelectorRole::SearchResult,
+    SelectorRole::SearchName,
+    SelectorRole::SearchAffiliation,
+    SelectorRole::SearchEmail,
+    SelectorRole::SearchInterests,
+    SelectorRole::SearchNext,
+];
+
+/// How often the selectors of one role matched on a live page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectorCheck {
+    /// What the selectors extract
+    pub role: SelectorRole,
+    /// The selectors of the role, several for alternative layouts
+    pub selectors: Vec<String>,
+    /// Number of matching elements of all selectors together
+    pub matches: usize,
+}
+
+impl SelectorCheck {
+    /// Whether any of the selectors matched
+    pub fn is_ok(&self) -> bool {
+        self.matches > 0
+    }
+}
+
+/// The outcome of a [`selftest`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelftestReport {
+    /// One check per role, in the order of [`known_selectors`](crate::known_selectors)
+    pub checks: Vec<SelectorCheck>,
+}
+
+impl SelftestReport {
+    /// Whether every checked role matched
+    pub fn is_ok(&self) -> bool {
+        self.checks.iter().all(SelectorCheck::is_ok)
+    }
+
+    /// Returns the checks of the roles that didn't match
+    pub fn failures(&self) -> impl Iterator<Item = &SelectorCheck> {
+        self.checks.iter().filter(|check| !check.is_ok())
+    }
+}
+
+impl fmt::Display for SelftestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = if check.is_ok() { "ok" } else { "FAILED" };
+            writeln!(
+                f,
+                "{status:>6}  {:?} ({} matches): {}",
+                check.role,
+                check.matches,
+                check.selectors.join(" | ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the known selectors against live Scholar pages
+///
+/// This is the canary for layout changes: it fetches the profile of
+/// [`EXAMPLE_AUTHOR_ID`], the detail page of its first publication and a
+/// search result page, waiting `options.politeness_delay` between them, and
+/// counts the matches of every selector on the page it belongs to. Roles that
+/// legitimately don't match on these pages (the consent form, the disclaimer,
+/// the mobile layout, and the homepage, co-authors and extra sections, which
+/// not every profile has) aren't checked.
+///
+/// # Returns
+///
+/// * `Result<SelftestReport>` - The checks, or an error if a page couldn't be fetched
+pub async fn selftest() -> Result<SelftestReport> {
+    selftest_with_options(&FetchOptions::default()).await
+}
+
+/// Same as [`selftest`], but with custom fetch options (e.g. a proxy or cookies)
+pub async fn selftest_with_options(options: &FetchOptions) -> Result<SelftestReport> {
+    let mut checks = Vec::new();
+
+    let document = fetch_html(&profile_url(EXAMPLE_AUTHOR_ID), options).await?;
+    checks.extend(check_roles(&document, &PROFILE_ROLES));
+    let detail_url = extract_publications(&document)
+        .into_iter()
+        .find_map(|publication| publication.url);
+    drop(document);
+
+    match detail_url {
+        Some(url) => {
+            sleep(options.politeness_delay).await;
+            let document = fetch_html(url.as_str(), options).await?;
+            checks.extend(check_roles(&document, &DETAIL_ROLES));
+        }
+        // Without a publication link the detail page can't be reached, which is a failure too
+        None => checks.extend(DETAIL_ROLES.iter().map(|&role| unmatched(role))),
+    }
+
+    sleep(options.politeness_delay).await;
+    let document = fetch_html(&search_url(SELFTEST_SEARCH, None), options).await?;
+    checks.extend(check_roles(&document, &SEARCH_ROLES));
+
+    Ok(SelftestReport { checks })
+}
+
+/// Counts the matches of the selectors of each role on a page
+fn check_roles(document: &Html, roles: &[SelectorRole]) -> Vec<SelectorCheck> {
+    roles
+        .iter()
+        .map(|&role| {
+            let mut check = unmatched(role);
+            for selector in &check.selectors {
+                if let Ok(parsed) = Selector::parse(selector) {
+                    check.matches += document.select(&parsed).count();
+                }
+            }
+            check
+        })
+        .collect()
+}
+
+/// A check of a role without any matches yet
+fn unmatched(role: SelectorRole) -> SelectorCheck {
+    SelectorCheck {
+        role,
+        selectors: known_selectors()
+            .iter()
+            .filter(|(known_role, _)| *known_role == role)
+            .map(|(_, selector)| selector.to_string())
+            .collect(),
+        matches: 0,
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    fetch_author_info, fetch_html, http_client, AuthorId, AuthorInfo, FetchOptions, ScholarClient,
+    ScraperError, CONSENT_COOKIE, SCHOLAR_LANGUAGE, SCHOLAR_URL,
+};
+use anyhow::Result;
+
+/// A warmed-up scraping session for production use
+///
+/// Creating a session visits the Scholar start page once. If Google asks for
+/// cookie consent, [`CONSENT_COOKIE`] is added and the visit repeated. One
+/// HTTP client is then kept for all requests, so connections stay alive and,
+/// with the `cookies` feature, cookies set by Scholar are sent back.
+#[derive(Debug, Clone)]
+pub struct ScholarSession {
+    client: ScholarClient,
+}
+
+impl ScholarSession {
+    /// Starts a session with the default fetch options
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self>` - The session, or why Scholar couldn't be reached (e.g. [`ScraperError::ConsentRequired`] if consent couldn't be given)
+    pub async fn new() -> Result<Self> {
+        Self::with_options(FetchOptions::default()).await
+    }
+
+    /// Same as [`ScholarSession::new`], but with custom fetch options
+    ///
+    /// The proxy, user agent, timeout and cookies of `options` are applied to
+    /// the session's client.
+    pub async fn with_options(options: FetchOptions) -> Result<Self> {
+        let client = ScholarClient::new(options);
+        match Self::warm_up(client).await {
+            Err(e) if e.error.downcast_ref() == Some(&ScraperError::ConsentRequired) => {
+                tracing::info!("Accepting Google's cookie consent");
+                Self::warm_up(Self::with_consent(e.client))
+                    .await
+                    .map_err(|e| e.error)
+            }
+            result => result.map_err(|e| e.error),
+        }
+    }
+
+    /// Returns the fetch options of this session, including its shared client
+    pub fn options(&self) -> &FetchOptions {
+        self.client.options()
+    }
+
+    /// Fetches an author's profile, see [`fetch_author_info`]
+    pub async fn author_info(
+        &self,
+        author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    ) -> Result<AuthorInfo> {
+        fetch_author_info(author_id, self.options()).await
+    }
+
+    /// Builds the shared HTTP client and visits the start page with it
+    async fn warm_up(mut client: ScholarClient) -> Result<Self, WarmUpError> {
+        let options = client.options_mut();
+        options.http_client = None;
+        match http_client(options) {
+            Ok(http) => options.http_client = Some(http),
+            Err(error) => return Err(WarmUpError { client, error }),
+        }
+
+        let start_page = format!("{SCHOLAR_URL}/?hl={SCHOLAR_LANGUAGE}");
+        match fetch_html(&start_page, client.options()).await {
+            Ok(_) => Ok(Self { client }),
+            Err(error) => Err(WarmUpError { client, error }),
+        }
+    }
+
+    /// Adds the consent cookie to the cookies sent by the client
+    fn with_consent(mut client: ScholarClient) -> ScholarClient {
+        let options = client.options_mut();
+        #[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+        if let Some(cookie_store) = &options.cookie_store {
+            cookie_store.add_cookie_str(CONSENT_COOKIE, &options.cors_proxy_url());
+            return client;
+        }
+
+        options.cookies = Some(match options.cookies.take() {
+            Some(cookies) => format!("{cookies}; {CONSENT_COOKIE}"),
+            None => CONSENT_COOKIE.to_string(),
+        });
+        client
+    }
+}
+
+/// A failed warm-up, returning the client for another attempt
+struct WarmUpError {
+    client: ScholarClient,
+    error: anyhow::Error,
+}
@@ -0,0 +1,108 @@
+use crate::AuthorInfo;
+use std::collections::BTreeSet;
+
+/// Weight of the name in [`similarity`]
+const NAME_WEIGHT: f64 = 0.5;
+/// Weight of the affiliation in [`similarity`]
+const AFFILIATION_WEIGHT: f64 = 0.2;
+/// Weight of the shared co-authors in [`similarity`]
+const COAUTHOR_WEIGHT: f64 = 0.3;
+
+/// Scores how likely two profiles belong to the same person
+///
+/// Combines the similarity of the names and of the affiliations (by edit
+/// distance, ignoring case and punctuation) and the overlap of the
+/// co-authors. A signal that neither profile has, like co-authors on two
+/// profiles without any, is left out and the others are weighted up.
+///
+/// # Arguments
+///
+/// * `a` - The first profile
+/// * `b` - The second profile
+///
+/// # Returns
+///
+/// * `f64` - The score from 0.0 (nothing in common) to 1.0 (identical)
+pub fn similarity(a: &AuthorInfo, b: &AuthorInfo) -> f64 {
+    let mut signals = vec![(NAME_WEIGHT, text_similarity(&a.name, &b.name))];
+
+    match (&a.affiliation, &b.affiliation) {
+        (None, None) => {}
+        (Some(a), Some(b)) => signals.push((AFFILIATION_WEIGHT, text_similarity(a, b))),
+        _ => signals.push((AFFILIATION_WEIGHT, 0.0)),
+    }
+
+    let coauthors = |info: &AuthorInfo| -> BTreeSet<String> {
+        info.coauthors
+            .iter()
+            .map(|coauthor| coauthor.author_id.clone())
+            .collect()
+    };
+    if let Some(overlap) = jaccard(&coauthors(a), &coauthors(b)) {
+        signals.push((COAUTHOR_WEIGHT, overlap));
+    }
+
+    let weights: f64 = signals.iter().map(|(weight, _)| weight).sum();
+    signals
+        .iter()
+        .map(|(weight, score)| weight * score)
+        .sum::<f64>()
+        / weights
+}
+
+/// Checks whether two profiles are likely split profiles of the same person
+///
+/// # Arguments
+///
+/// * `a` - The first profile
+/// * `b` - The second profile
+/// * `threshold` - The minimum [`similarity`], e.g. 0.8
+///
+/// # Returns
+///
+/// * `bool` - Whether the similarity reaches the threshold
+pub fn likely_same(a: &AuthorInfo, b: &AuthorInfo, threshold: f64) -> bool {
+    similarity(a, b) >= threshold
+}
+
+/// Similarity of two names or affiliations from 0.0 to 1.0, by edit distance of their normalized forms
+fn text_similarity(a: &str, b: &str) -> f64 {
+    let normalize = |text: &str| -> Vec<char> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase()
+            .chars()
+            .collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 0.0;
+    }
+    1.0 - levenshtein(&a, &b) as f64 / longest as f64
+}
+
+/// Number of inserted, removed or replaced characters to turn `a` into `b`
+fn levenshtein(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let replace = previous[j] + usize::from(ca != cb);
+            current[j + 1] = replace.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Share of the elements of two sets that both have, `None` if both are empty
+fn jaccard(a: &BTreeSet<String>, b: &BTreeSet<String>) -> Option<f64> {
+    let union = a.union(b).count();
+    (union > 0).then(|| a.intersection(b).count() as f64 / union as f64)
+}
@@ -0,0 +1,169 @@
+use crate::{AuthorInfo, ScholarClient};
+use anyhow::Result;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture;
+use std::fmt;
+
+/// Future returned by [`AuthorSource::fetch_author`]
+///
+/// It is `Send` on native targets, so a source can be polled on a
+/// multi-threaded runtime. In the browser requests aren't `Send`, and neither
+/// is the future.
+#[cfg(not(target_arch = "wasm32"))]
+pub type SourceFuture<'a> = BoxFuture<'a, Result<AuthorInfo>>;
+/// Future returned by [`AuthorSource::fetch_author`]
+#[cfg(target_arch = "wasm32")]
+pub type SourceFuture<'a> = LocalBoxFuture<'a, Result<AuthorInfo>>;
+
+/// Bounds of every [`AuthorSource`]: `Send + Sync` on native targets, none in the browser
+///
+/// A [`SourceChain`] can then be shared between threads (e.g. behind an
+/// `Arc` in a server) and its futures spawned on a multi-threaded runtime.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait SourceBounds: Send + Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + Sync + ?Sized> SourceBounds for T {}
+/// Bounds of every [`AuthorSource`]: `Send + Sync` on native targets, none in the browser
+#[cfg(target_arch = "wasm32")]
+pub trait SourceBounds {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> SourceBounds for T {}
+
+/// A service that profiles can be fetched from, e.g. Scholar or a fallback like OpenAlex
+///
+/// [`ScholarClient`] implements it. Other services, such as Semantic Scholar
+/// or OpenAlex, are added by implementing it for a client of that service,
+/// mapping its data to [`AuthorInfo`] (and, if needed, the Scholar ID to the
+/// service's own ID). Each source carries its own options.
+pub trait AuthorSource: SourceBounds {
+    /// Short name of the source, recorded in [`SourcedAuthorInfo::source`]
+    fn name(&self) -> &str;
+
+    /// Fetches the profile of an author, given their Google Scholar ID
+    fn fetch_author<'a>(&'a self, author_id: &'a str) -> SourceFuture<'a>;
+}
+
+impl AuthorSource for ScholarClient {
+    fn name(&self) -> &str {
+        "scholar"
+    }
+
+    fn fetch_author<'a>(&'a self, author_id: &'a str) -> SourceFuture<'a> {
+        Box::pin(self.fetch_author_info(author_id))
+    }
+}
+
+/// A profile fetched by a [`SourceChain`], with the source that answered
+#[derive(Debug, Clone)]
+pub struct SourcedAuthorInfo {
+    /// The fetched profile
+    pub author_info: AuthorInfo,
+    /// [`AuthorSource::name`] of the source that answered
+    pub source: String,
+}
+
+/// The errors of all sources of a [`SourceChain`] after none of them succeeded
+#[derive(Debug, thiserror::Error)]
+pub struct SourceChainError {
+    /// [`AuthorSource::name`] and error of every source, in the order they were tried
+    pub errors: Vec<(String, anyhow::Error)>,
+}
+
+impl fmt::Display for SourceChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.errors.is_empty() {
+            return f.write_str("No source configured");
+        }
+        f.write_str("All sources failed: ")?;
+        for (i, (source, error)) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{source}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Ordered fallback chain of sources for fetching profiles
+///
+/// Sources are tried in the order they were added until one succeeds, e.g.
+/// Scholar first, then Semantic Scholar, then OpenAlex. A source isn't tried
+/// again within the same fetch, so its own retries (like the
+/// [`RetryPolicy`](crate::RetryPolicy) of a [`ScholarClient`]) happen before
+/// the chain moves on.
+///
+/// ```ignore
+/// let chain = SourceChain::new()
+///     .with_source(ScholarClient::new(FetchOptions::default()))
+///     .with_source(OpenAlexSource::new());
+/// let sourced = chain.fetch_author(EXAMPLE_AUTHOR_ID).await?;
+/// println!("{} from {}", sourced.author_info.name, sourced.source);
+/// ```
+#[derive(Default)]
+pub struct SourceChain {
+    sources: Vec<Box<dyn AuthorSource>>,
+}
+
+impl SourceChain {
+    /// Creates a chain without sources
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a source, tried after the ones added before
+    pub fn with_source(mut self, source: impl AuthorSource + 'static) -> Self {
+        self.sources.push(Box::new(source));
+        self
+    }
+
+    /// Returns the names of the sources, in the order they are tried
+    pub fn source_names(&self) -> Vec<&str> {
+        self.sources.iter().map(|source| source.name()).collect()
+    }
+
+    /// Fetches an author from the first source that succeeds
+    ///
+    /// The errors of the sources tried before are logged. If no source
+    /// succeeds, all errors are returned together as a [`SourceChainError`],
+    /// from which the error of each source can be recovered (e.g. with
+    /// `ScraperError::from` for the Scholar one).
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The Google Scholar ID of the author
+    ///
+    /// # Returns
+    ///
+    /// * `Result<SourcedAuthorInfo>` - The profile with the name of the source that answered
+    pub async fn fetch_author(&self, author_id: &str) -> Result<SourcedAuthorInfo> {
+        let mut errors = Vec::new();
+
+        for source in &self.sources {
+            match source.fetch_author(author_id).await {
+                Ok(author_info) => {
+                    return Ok(SourcedAuthorInfo {
+                        author_info,
+                        source: source.name().to_string(),
+                    })
+                }
+                Err(e) => {
+                    tracing::warn!("Source {} failed for {author_id}: {e}", source.name());
+                    errors.push((source.name().to_string(), e));
+                }
+            }
+        }
+
+        Err(SourceChainError { errors }.into())
+    }
+}
+
+impl fmt::Debug for SourceChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SourceChain")
+            .field("sources", &self.source_names())
+            .finish()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    fetch_author_info, recommended_poll_interval, retry::sleep, to_author_id, AuthorDiff, AuthorId,
+    AuthorInfo, FetchOptions,
+};
+use anyhow::Result;
+use std::path::Path;
+use std::time::Duration;
+
+/// Periodically re-scrapes a profile and reports what changed
+///
+/// The last snapshot is stored as JSON at `snapshot_path`, so a restarted
+/// watcher compares against the same baseline. `on_change` is called with the
+/// changes of every poll that found any; use the [`Display`](std::fmt::Display)
+/// impl of [`AuthorDiff`] to print them. Failed polls are logged and retried
+/// at the next interval. The interval is never shorter than the politeness delay,
+/// and intervals below [`recommended_poll_interval`] log a warning.
+///
+/// This runs until writing the snapshot fails.
+///
+/// # Arguments
+///
+/// * `author_id` - The Google Scholar ID of the author
+/// * `interval` - The time between polls
+/// * `snapshot_path` - The file holding the last snapshot
+/// * `options` - The fetch options
+/// * `on_change` - Called with the changes since the previous snapshot
+pub async fn watch(
+    author_id: impl TryInto<AuthorId, Error: Into<anyhow::Error>>,
+    interval: Duration,
+    snapshot_path: &Path,
+    options: &FetchOptions,
+    mut on_change: impl FnMut(&AuthorDiff),
+) -> Result<()> {
+    if interval < recommended_poll_interval() {
+        tracing::warn!(
+            "Polling every {}s, more often than the recommended {}s",
+            interval.as_secs(),
+            recommended_poll_interval().as_secs()
+        );
+    }
+    let author_id = to_author_id(author_id)?;
+    let interval = interval.max(options.politeness_delay);
+    let mut previous = load_snapshot(snapshot_path);
+
+    loop {
+        match fetch_author_info(&author_id, options).await {
+            Ok(current) => {
+                if let Some(previous) = &previous {
+                    let diff = previous.diff(&current);
+                    if !diff.is_empty() {
+                        on_change(&diff);
+                    }
+                }
+                std::fs::write(snapshot_path, serde_json::to_string(&current)?)?;
+                previous = Some(current);
+            }
+            Err(e) => tracing::warn!("Polling {author_id} failed: {e}"),
+        }
+
+        sleep(interval).await;
+    }
+}
+
+/// Loads the stored snapshot, `None` if there is none or it can't be read
+fn load_snapshot(path: &Path) -> Option<AuthorInfo> {
+    let content = std::fs::read_to_string(path).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(snapshot) => Some(snapshot),
+        Err(e) => {
+            tracing::warn!("Ignoring unreadable snapshot {}: {e}", path.display());
+            None
+        }
+    }
+}
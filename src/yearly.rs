@@ -0,0 +1,308 @@
+use crate::{retry::unix_now, AuthorInfo};
+use std::collections::BTreeMap;
+
+/// Returns the yearly citations of an author for every year of their range
+///
+/// Years between the first and the last year without a count are filled in
+/// with zero, which suits plotting libraries that expect contiguous series.
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `Vec<(usize, usize)>` - (year, citations) in ascending year order, empty if there are no years
+pub fn dense_yearly_citations(author: &AuthorInfo) -> Vec<(usize, usize)> {
+    let yearly = &author.yearly_citations;
+    let (Some(&first), Some(&last)) = (yearly.keys().next(), yearly.keys().next_back()) else {
+        return Vec::new();
+    };
+
+    (first..=last)
+        .map(|year| (year, yearly.get(&year).copied().unwrap_or(0)))
+        .collect()
+}
+
+/// Returns the yearly citations of an author for every year of their range, with gaps kept
+///
+/// Unlike [`dense_yearly_citations`], a year without a bar in Scholar's
+/// histogram is `None` rather than zero, while a year Scholar reported with no
+/// citations is `Some(0)`. This lets a chart draw missing data as a gap
+/// instead of a drop to zero.
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `Vec<(usize, Option<usize>)>` - (year, citations) in ascending year order, empty if there are no years
+pub fn yearly_citations_with_gaps(author: &AuthorInfo) -> Vec<(usize, Option<usize>)> {
+    let yearly = &author.yearly_citations;
+    let Some((first, last)) = histogram_span(author) else {
+        return Vec::new();
+    };
+
+    (first..=last)
+        .map(|year| (year, yearly.get(&year).copied()))
+        .collect()
+}
+
+/// Returns each year's share of an author's citations, to compare the shapes of trajectories
+///
+/// The shares are relative to the sum of the histogram rather than
+/// [`AuthorInfo::total`], which also counts years the histogram doesn't show,
+/// so they add up to 1.
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `BTreeMap<usize, f64>` - The share (0.0 to 1.0) per year, all zero if there are no citations
+pub fn yearly_citations_normalized(author: &AuthorInfo) -> BTreeMap<usize, f64> {
+    let sum: usize = author.yearly_citations.values().sum();
+
+    author
+        .yearly_citations
+        .iter()
+        .map(|(&year, &citations)| {
+            let share = if sum == 0 {
+                0.0
+            } else {
+                citations as f64 / sum as f64
+            };
+            (year, share)
+        })
+        .collect()
+}
+
+/// Aligns the yearly citations of two authors for a side-by-side comparison
+///
+/// The result spans every year from the earliest to the latest year of either
+/// author, so non-overlapping ranges and gaps are covered too. Years an author
+/// has no count for are zero.
+///
+/// # Arguments
+///
+/// * `a` - The first author
+/// * `b` - The second author
+///
+/// # Returns
+///
+/// * `Vec<(usize, usize, usize)>` - (year, citations of `a`, citations of `b`) in ascending year order
+pub fn align_trajectories(a: &AuthorInfo, b: &AuthorInfo) -> Vec<(usize, usize, usize)> {
+    let years = a.yearly_citations.keys().chain(b.yearly_citations.keys());
+    let (Some(&first), Some(&last)) = (years.clone().min(), years.max()) else {
+        return Vec::new();
+    };
+
+    (first..=last)
+        .map(|year| {
+            (
+                year,
+                a.yearly_citations.get(&year).copied().unwrap_or(0),
+                b.yearly_citations.get(&year).copied().unwrap_or(0),
+            )
+        })
+        .collect()
+}
+
+/// Estimates the first year of an author's career
+///
+/// This is the earliest publication year if publications were fetched,
+/// otherwise the earliest year with citations. Both are only estimates:
+/// Scholar's histogram starts late for long careers, and early work may be
+/// missing from the profile.
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `Option<usize>` - The estimated start year, `None` if the profile has no dated data
+pub fn estimated_career_start(author: &AuthorInfo) -> Option<usize> {
+    let first_publication = author
+        .publications
+        .iter()
+        .filter_map(|publication| publication.year)
+        .min();
+
+    first_publication.or_else(|| {
+        author
+            .yearly_citations
+            .iter()
+            .find(|(_, &citations)| citations > 0)
+            .map(|(&year, _)| year)
+    })
+}
+
+/// Returns the first and the last year of the citation histogram
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `Option<(usize, usize)>` - (first year, last year), `None` if the histogram is empty
+pub fn histogram_span(author: &AuthorInfo) -> Option<(usize, usize)> {
+    let yearly = &author.yearly_citations;
+    Some((*yearly.keys().next()?, *yearly.keys().next_back()?))
+}
+
+/// Returns the number of years in the citation histogram
+///
+/// Gap years without a bar aren't counted, see [`dense_yearly_citations`] for all years of the span.
+pub fn histogram_year_count(author: &AuthorInfo) -> usize {
+    author.yearly_citations.len()
+}
+
+/// Returns the latest year of the citation histogram
+///
+/// Scholar doesn't say when a profile was last updated, but a histogram that
+/// ends years ago hints that the profile (or Scholar's crawl of it) is stale.
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `Option<usize>` - The latest year with a count, `None` if the histogram is empty
+pub fn latest_data_year(author: &AuthorInfo) -> Option<usize> {
+    author.yearly_citations.keys().next_back().copied()
+}
+
+/// Checks whether the citation histogram already has a bar for the current year
+///
+/// # Arguments
+///
+/// * `author` - The author
+///
+/// # Returns
+///
+/// * `bool` - Whether the latest year of the histogram is the current (UTC) year
+pub fn has_current_year_data(author: &AuthorInfo) -> bool {
+    latest_data_year(author) == Some(current_year())
+}
+
+/// Averages the citations of the most recent years of the histogram
+///
+/// The current year counts like any other, although its bar only covers the
+/// months so far and pulls the average down for most of the year. Use
+/// [`mean_citations_last_n_years_with_options`] or
+/// [`AuthorInfo::without_current_year`] to leave it out.
+///
+/// # Arguments
+///
+/// * `author` - The author
+/// * `n` - The number of years, the latest `n` years present in the histogram are averaged
+///
+/// # Returns
+///
+/// * `f64` - The mean citations per year, over fewer years if the histogram has fewer than `n`, 0.0 if it's empty or `n` is 0
+pub fn mean_citations_last_n_years(author: &AuthorInfo, n: usize) -> f64 {
+    mean_citations_last_n_years_with_options(author, n, true)
+}
+
+/// Same as [`mean_citations_last_n_years`], but can leave out the current year
+///
+/// With `include_current_year` false, the window ends at the latest year
+/// before the current (UTC) year, so `n` complete years are averaged.
+pub fn mean_citations_last_n_years_with_options(
+    author: &AuthorInfo,
+    n: usize,
+    include_current_year: bool,
+) -> f64 {
+    let current_year = current_year();
+    let window: Vec<usize> = author
+        .yearly_citations
+        .iter()
+        .rev()
+        .filter(|(&year, _)| include_current_year || year < current_year)
+        .take(n)
+        .map(|(_, &citations)| citations)
+        .collect();
+
+    if window.is_empty() {
+        return 0.0;
+    }
+    window.iter().sum::<usize>() as f64 / window.len() as f64
+}
+
+impl AuthorInfo {
+    /// Removes the current year from the citation histogram, if it's the latest year
+    ///
+    /// The current year's bar only counts the citations so far, so trends,
+    /// peak years and averages computed with it are skewed low. For analysis,
+    /// strip it first (or set [`FetchOptions::drop_current_year`](crate::FetchOptions::drop_current_year))
+    /// and pass the result to helpers like [`dense_yearly_citations`] or
+    /// [`yearly_citations_normalized`]. The summary metrics are kept, as
+    /// Scholar counts the current year in them.
+    ///
+    /// # Returns
+    ///
+    /// * `AuthorInfo` - The profile without the current year's bar
+    pub fn without_current_year(mut self) -> Self {
+        if has_current_year_data(&self) {
+            self.yearly_citations.remove(&current_year());
+        }
+        self
+    }
+}
+
+/// Returns the current year in UTC
+fn current_year() -> usize {
+    year_of_unix_secs(unix_now().as_secs())
+}
+
+/// Returns the year in UTC of a Unix timestamp
+///
+/// This is the clock behind [`has_current_year_data`] and
+/// [`AuthorInfo::without_current_year`], without a dependency on a date crate.
+///
+/// # Arguments
+///
+/// * `secs` - The seconds since the Unix epoch
+///
+/// # Returns
+///
+/// * `usize` - The year, e.g. 2024
+pub(crate) fn year_of_unix_secs(secs: u64) -> usize {
+    let days = (secs / 86_400) as i64;
+    // Civil year of a day count, after Howard Hinnant's `civil_from_days`
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    // The computation's years start in March, so January and February belong to the next one
+    let year = year_of_era + era * 400 + i64::from(month_index >= 10);
+    year as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn year_of_known_timestamps() {
+        assert_eq!(year_of_unix_secs(0), 1970);
+        // New Year's Eve and Day
+        assert_eq!(year_of_unix_secs(946_684_799), 1999);
+        assert_eq!(year_of_unix_secs(946_684_800), 2000);
+        assert_eq!(year_of_unix_secs(1_735_689_599), 2024);
+        assert_eq!(year_of_unix_secs(1_735_689_600), 2025);
+        // Leap days, and the end of February in a century that isn't a leap year
+        assert_eq!(year_of_unix_secs(951_782_400), 2000);
+        assert_eq!(year_of_unix_secs(1_709_208_000), 2024);
+        assert_eq!(year_of_unix_secs(4_107_542_399), 2100);
+        assert_eq!(year_of_unix_secs(4_107_542_400), 2100);
+    }
+}
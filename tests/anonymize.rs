@@ -0,0 +1,94 @@
+use leptos_scholar::{
+    anonymize, anonymize_with_key, parse_author_info, AuthorInfo, CitationLinks, FetchOptions,
+    Publication,
+};
+use scraper::Html;
+use url::Url;
+
+#[test]
+fn no_identifying_string_survives() {
+    let document = Html::parse_document(include_str!("fixtures/coauthor_cards.html"));
+    let mut info = parse_author_info(&document, &FetchOptions::default()).unwrap();
+    let url = |url: &str| Url::parse(url).unwrap();
+    info.affiliation = Some("Example University".to_string());
+    info.verified_email_domains = vec!["example.edu".to_string()];
+    info.photo_url = Some(url(
+        "https://scholar.google.com/citations?view_op=view_photo&user=DOE789AAAAJ",
+    ));
+    info.homepage = Some(url("https://jane-doe.example.org/"));
+    info.citation_links = Some(CitationLinks {
+        all: [(
+            "Citations".to_string(),
+            url("https://scholar.google.com/citations?user=DOE789AAAAJ&view_op=list_works"),
+        )]
+        .into(),
+        recent: Default::default(),
+    });
+    info.extra_metrics = [(
+        "Public access".to_string(),
+        "Jane Doe: 3 articles".to_string(),
+    )]
+    .into();
+    info.publications = vec![Publication {
+        title: "Spin waves in Doe lattices".to_string(),
+        authors: vec!["J Doe".to_string(), "J Roe".to_string()],
+        venue: Some("Physical Review B".to_string()),
+        year: Some(2021),
+        citations: 12,
+        cite_id: Some("DOE789AAAAJ:u5HHmVD_uO8C".to_string()),
+        url: Some(url("https://scholar.google.com/citations?view_op=view_citation&citation_for_view=DOE789AAAAJ:u5HHmVD_uO8C")),
+        cited_by_url: Some(url("https://scholar.google.com/scholar?cites=1234567890")),
+    }];
+
+    let anonymized = serde_json::to_string(&anonymize(&info)).unwrap();
+    for original in [
+        "Jane Doe",
+        "Doe",
+        "DOE789AAAAJ",
+        "Example University",
+        "example.edu",
+        "jane-doe.example.org",
+        "John Roe",
+        "ROE123AAAAJ",
+        "Ann Poe",
+        "POE456AAAAJ",
+        "Example Institute",
+        "Research Scientist",
+        "Spin waves",
+        "J Roe",
+        "cites=1234567890",
+        "small_photo",
+    ] {
+        assert!(
+            !anonymized.contains(original),
+            "{original:?} survived in {anonymized}"
+        );
+    }
+
+    // The metrics are kept
+    let anonymized = anonymize(&info);
+    assert_eq!((anonymized.total, anonymized.h_index), (42, 3));
+    assert_eq!(anonymized.publications[0].citations, 12);
+}
+
+#[test]
+fn keyed_pseudonyms_depend_on_the_key() {
+    let document = Html::parse_document(include_str!("fixtures/coauthor_cards.html"));
+    let info = parse_author_info(&document, &FetchOptions::default()).unwrap();
+    let names = |info: &AuthorInfo| {
+        let mut names = vec![info.name.clone()];
+        names.extend(info.coauthors.iter().map(|coauthor| coauthor.name.clone()));
+        names
+    };
+
+    let first = names(&anonymize_with_key(&info, &[1; 16]));
+    assert_eq!(first, names(&anonymize_with_key(&info, &[1; 16])));
+    assert!(first.iter().all(|name| name.starts_with("author-")));
+
+    let other_key = names(&anonymize_with_key(&info, &[2; 16]));
+    let unkeyed = names(&anonymize(&info));
+    for (index, name) in first.iter().enumerate() {
+        assert_ne!(*name, other_key[index]);
+        assert_ne!(*name, unkeyed[index]);
+    }
+}
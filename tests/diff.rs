@@ -0,0 +1,74 @@
+use leptos_scholar::{parse_author_page, AuthorDiff, AuthorInfo, FetchOptions, Publication};
+
+fn snapshot() -> AuthorInfo {
+    parse_author_page(
+        include_str!("fixtures/accented_name.html"),
+        &FetchOptions::default(),
+    )
+    .unwrap()
+}
+
+fn publication(title: &str) -> Publication {
+    Publication {
+        title: title.to_string(),
+        authors: Vec::new(),
+        venue: None,
+        year: Some(2024),
+        citations: 0,
+        cite_id: None,
+        url: None,
+        cited_by_url: None,
+    }
+}
+
+#[test]
+fn unchanged_profile_has_empty_diff() {
+    let diff = snapshot().diff(&snapshot());
+    assert!(diff.is_empty());
+    assert_eq!(diff, AuthorDiff::default());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn changed_metrics() {
+    let mut newer = snapshot();
+    newer.total = 50;
+    newer.h_index = 4;
+    newer.yearly_citations.insert(2023, 38);
+
+    let diff = snapshot().diff(&newer);
+    assert_eq!(diff.total, Some((42, 50)));
+    assert_eq!(diff.h_index, Some((3, 4)));
+    assert_eq!(diff.i10_index, None);
+    assert_eq!(diff.yearly_citations, [(2023, (30, 38))].into());
+}
+
+#[test]
+fn new_year_and_publication() {
+    let older = snapshot();
+    let mut newer = snapshot();
+    newer.yearly_citations.insert(2024, 5);
+    newer.publications.push(publication("A new result"));
+
+    let diff = older.diff(&newer);
+    // A year missing from the older snapshot counts as 0
+    assert_eq!(diff.yearly_citations, [(2024, (0, 5))].into());
+    assert_eq!(diff.new_publications, ["A new result"]);
+}
+
+#[test]
+fn diff_is_displayed_one_line_per_change() {
+    let mut newer = snapshot();
+    newer.total = 40;
+    newer.i10_index = 2;
+    newer.yearly_citations.insert(2024, 5);
+    newer.publications.push(publication("A new result"));
+
+    assert_eq!(
+        snapshot().diff(&newer).to_string(),
+        "citations: 42 -> 40 (-2)\n\
+         i10-index: 1 -> 2 (+1)\n\
+         citations in 2024: 0 -> 5 (+5)\n\
+         new publication: A new result\n"
+    );
+}
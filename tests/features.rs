@@ -0,0 +1,103 @@
+//! Smoke tests of the feature-gated API, without any network access
+//!
+//! Each test only exists with its feature, so every feature can be checked in
+//! isolation, e.g. `cargo test --no-default-features --features cookies --test features`.
+//! `gzip` and `brotli` add no API of their own and are covered by building
+//! with them. Futures are created but never polled, so no request is sent.
+
+use leptos_scholar::*;
+
+#[test]
+fn core_entry_points() {
+    let options = FetchOptions::default();
+    let author_id: AuthorId = EXAMPLE_AUTHOR_ID.parse().unwrap();
+
+    drop(fetch_author_info(&author_id, &options));
+    drop(fetch_author_info_with_meta(&author_id, &options));
+    drop(fetch_metrics(&author_id, &options));
+    drop(fetch_publications(&author_id, &options));
+    drop(fetch_info(author_id.to_string()));
+    drop(search_authors("Jane Doe"));
+    drop(health_check());
+    drop(fetch_many(&[], 1, &options));
+    assert!(!known_selectors().is_empty());
+}
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn native_entry_points() {
+    let options = FetchOptions::default();
+
+    drop(fetch_author_info_deadline(
+        EXAMPLE_AUTHOR_ID,
+        std::time::Duration::from_secs(30),
+        &options,
+    ));
+}
+
+#[test]
+#[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+fn cookies_seed_the_store() {
+    let client = ScholarClient::with_cookies(FetchOptions::default(), "SOCS=abc; NID=def");
+
+    assert!(client.options().cookie_store.is_some());
+}
+
+#[test]
+#[cfg(all(feature = "cookies", not(target_arch = "wasm32")))]
+fn cookie_store_of_the_caller_is_kept() {
+    let jar = std::sync::Arc::new(reqwest::cookie::Jar::default());
+    let options = FetchOptions {
+        cookie_store: Some(jar.clone()),
+        ..FetchOptions::default()
+    };
+
+    let client = ScholarClient::new(options.clone());
+    assert!(std::sync::Arc::ptr_eq(
+        client.options().cookie_store.as_ref().unwrap(),
+        &jar
+    ));
+    let client = ScholarClient::with_cookies(options, "SOCS=abc");
+    assert!(std::sync::Arc::ptr_eq(
+        client.options().cookie_store.as_ref().unwrap(),
+        &jar
+    ));
+}
+
+#[test]
+#[cfg(feature = "socks")]
+fn socks_proxy_identity_rotates() {
+    let mut client = ScholarClient::new(FetchOptions {
+        proxy: Some("socks5h://127.0.0.1:9050".to_string()),
+        ..FetchOptions::default()
+    });
+
+    client.rotate_identity().unwrap();
+    let first = client.options().proxy.clone().unwrap();
+    client.rotate_identity().unwrap();
+
+    assert!(first.starts_with("socks5h://scholar:"));
+    assert_ne!(client.options().proxy.as_deref(), Some(first.as_str()));
+}
+
+#[test]
+#[cfg(feature = "crossref")]
+fn crossref_lookup() {
+    let options = CrossrefOptions {
+        mailto: Some("lab@example.org".to_string()),
+        ..CrossrefOptions::default()
+    };
+
+    drop(resolve_doi_crossref("A Great Paper", Some(2020)));
+    drop(resolve_doi_crossref_with_options(
+        "A Great Paper",
+        None,
+        &options,
+    ));
+}
+
+#[test]
+#[cfg(feature = "app")]
+fn app_component() {
+    let _component = ScholarProfile;
+}
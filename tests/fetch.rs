@@ -0,0 +1,305 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use leptos_scholar::{
+    check_ids_with_options, fetch_author_info, fetch_publications, health_check_with_options,
+    AuthorId, FetchOptions, HealthState, IdStatus, NetworkErrorKind, RateLimiter, RetryPolicy,
+    ScholarClient, ScraperError, EXAMPLE_AUTHOR_ID,
+};
+use std::fs;
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Serves every connection on a local port with `respond`, returns the options to fetch from it
+fn serve(respond: fn(TcpStream)) -> FetchOptions {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                    line.clear();
+                }
+                respond(stream);
+            });
+        }
+    });
+    FetchOptions {
+        cors_proxy: Some(format!("http://{address}/?quest=")),
+        retry: RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        },
+        timeout: Some(Duration::from_secs(10)),
+        ..FetchOptions::default()
+    }
+}
+
+/// Answers with status 200 and the fixture at `path`
+fn write_fixture(mut stream: TcpStream, path: &str) {
+    let body = fs::read(path).unwrap();
+    let _ = write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n",
+        body.len()
+    );
+    let _ = stream.write_all(&body);
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn chunked_response_over_the_limit_is_cut_off() {
+    // No `Content-Length`, a body that never ends
+    let mut options = serve(|mut stream| {
+        let _ = stream.write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nTransfer-Encoding: chunked\r\n\r\n",
+        );
+        let chunk = format!("400\r\n{}\r\n", "x".repeat(0x400));
+        while stream.write_all(chunk.as_bytes()).is_ok() {}
+    });
+    options.max_html_bytes = 64 * 1024;
+
+    let error = block_on(fetch_author_info(EXAMPLE_AUTHOR_ID, &options)).unwrap_err();
+    match error.downcast_ref::<ScraperError>() {
+        Some(ScraperError::ResponseTooLarge(bytes)) => assert!(*bytes > 64 * 1024),
+        _ => panic!("expected ResponseTooLarge, got {error:?}"),
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn gzip_encoded_profile_is_decompressed() {
+    let options = serve(|mut stream| {
+        use flate2::{write::GzEncoder, Compression};
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&fs::read("tests/fixtures/accented_name.html").unwrap())
+            .unwrap();
+        let body = encoder.finish().unwrap();
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        );
+        let _ = stream.write_all(&body);
+    });
+
+    let author_info = block_on(fetch_author_info(EXAMPLE_AUTHOR_ID, &options)).unwrap();
+    assert_eq!(author_info.total, 42);
+    assert_eq!(author_info.h_index, 3);
+}
+
+#[test]
+fn author_id_is_checked_before_any_request() {
+    let options = serve(|_| panic!("no request expected"));
+    for author_id in ["", "H7sOPf8AAAA", "H7sOPf8AAAAJ/../"] {
+        let error = block_on(fetch_author_info(author_id, &options)).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<ScraperError>(),
+            Some(&ScraperError::InvalidId)
+        );
+    }
+
+    // Every form of an ID is accepted
+    let author_id: AuthorId = EXAMPLE_AUTHOR_ID.parse().unwrap();
+    drop(fetch_author_info(EXAMPLE_AUTHOR_ID, &options));
+    drop(fetch_author_info(EXAMPLE_AUTHOR_ID.to_string(), &options));
+    drop(fetch_author_info(&EXAMPLE_AUTHOR_ID.to_string(), &options));
+    drop(fetch_author_info(&author_id, &options));
+    drop(fetch_author_info(author_id, &options));
+}
+
+#[test]
+fn unavailable_page_is_retried() {
+    static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+    let mut options = serve(|mut stream| {
+        REQUESTS.fetch_add(1, Ordering::SeqCst);
+        let body = fs::read("tests/fixtures/unavailable_page.html").unwrap();
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\n\r\n",
+            body.len()
+        );
+        let _ = stream.write_all(&body);
+    });
+    options.retry = RetryPolicy {
+        max_retries: 2,
+        base_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(10),
+    };
+
+    // Served with status 200, but still the same as a 503
+    let error = block_on(fetch_author_info(EXAMPLE_AUTHOR_ID, &options)).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ScraperError>(),
+        Some(&ScraperError::ServiceUnavailable)
+    );
+    assert_eq!(REQUESTS.load(Ordering::SeqCst), 3);
+}
+
+/// Fetches the example author and returns the kind of the network error it fails with
+fn network_error_kind(options: &FetchOptions) -> Option<NetworkErrorKind> {
+    let error = block_on(fetch_author_info(EXAMPLE_AUTHOR_ID, options)).unwrap_err();
+    match error.downcast_ref::<ScraperError>() {
+        Some(ScraperError::Network(kind, _)) => Some(*kind),
+        _ => {
+            eprintln!("not a network error: {error:?}");
+            None
+        }
+    }
+}
+
+#[test]
+fn network_errors_are_classified() {
+    // No response at all
+    let mut options = serve(|stream| {
+        thread::sleep(Duration::from_secs(5));
+        drop(stream);
+    });
+    options.timeout = Some(Duration::from_millis(200));
+    assert_eq!(
+        network_error_kind(&options),
+        Some(NetworkErrorKind::Timeout)
+    );
+
+    // Connection closed in the middle of the response
+    let options = serve(|mut stream| {
+        let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\n<html>");
+    });
+    assert_eq!(
+        network_error_kind(&options),
+        Some(NetworkErrorKind::ConnectionReset)
+    );
+
+    // Plain HTTP where TLS is expected
+    let mut options = serve(|mut stream| {
+        let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n");
+    });
+    options.cors_proxy = options
+        .cors_proxy
+        .map(|proxy| proxy.replace("http://", "https://"));
+    assert_eq!(network_error_kind(&options), Some(NetworkErrorKind::Tls));
+
+    // A name that never resolves (RFC 2606)
+    options.cors_proxy = Some("http://scholar-proxy.invalid/?quest=".to_string());
+    assert_eq!(network_error_kind(&options), Some(NetworkErrorKind::Dns));
+}
+
+#[test]
+fn robot_check_leaves_ids_undecided() {
+    let options = serve(|stream| write_fixture(stream, "tests/fixtures/robot_check.html"));
+    let statuses = block_on(check_ids_with_options(
+        &[EXAMPLE_AUTHOR_ID.to_string()],
+        &options,
+    ));
+    assert_eq!(statuses[0].1, IdStatus::RateLimited);
+
+    let options = serve(|stream| write_fixture(stream, "tests/fixtures/accented_name.html"));
+    let statuses = block_on(check_ids_with_options(
+        &[EXAMPLE_AUTHOR_ID.to_string()],
+        &options,
+    ));
+    assert_eq!(statuses[0].1, IdStatus::Valid);
+}
+
+#[test]
+fn id_checks_wait_for_the_rate_limiter() {
+    let mut options = serve(|stream| write_fixture(stream, "tests/fixtures/accented_name.html"));
+    options.politeness_delay = Duration::ZERO;
+    options.rate_limiter = Some(RateLimiter::new(Duration::from_millis(300)));
+    let ids = vec![EXAMPLE_AUTHOR_ID.to_string(); 3];
+
+    let started = Instant::now();
+    let statuses = block_on(check_ids_with_options(&ids, &options));
+    assert!(statuses
+        .iter()
+        .all(|(_, status)| *status == IdStatus::Valid));
+    assert!(started.elapsed() >= Duration::from_millis(600));
+}
+
+#[test]
+fn publications_with_the_same_title_are_kept() {
+    let options =
+        serve(|stream| write_fixture(stream, "tests/fixtures/same_title_publications.html"));
+    let publications = block_on(fetch_publications(EXAMPLE_AUTHOR_ID, &options)).unwrap();
+
+    // Two editorials and a journal and conference version, the repeated row merged
+    assert_eq!(publications.len(), 4);
+    let editorials: Vec<_> = publications
+        .iter()
+        .filter(|publication| publication.title == "Editorial")
+        .map(|publication| (publication.year, publication.citations))
+        .collect();
+    assert_eq!(editorials, [(Some(2022), 1), (Some(2021), 4)]);
+}
+
+#[test]
+fn health_check_waits_for_the_rate_limiter() {
+    let mut options = serve(|stream| write_fixture(stream, "tests/fixtures/accented_name.html"));
+    options.rate_limiter = Some(RateLimiter::new(Duration::from_millis(300)));
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        let status = block_on(health_check_with_options(&options)).unwrap();
+        assert_eq!(status.state, HealthState::Reachable);
+    }
+    assert!(started.elapsed() >= Duration::from_millis(600));
+}
+
+#[test]
+fn rotated_identity_reaches_a_shared_client() {
+    // An HTTP proxy that reports the credentials of every request
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let (sender, credentials) = mpsc::channel();
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            let mut authorization = None;
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("proxy-authorization") {
+                        authorization = Some(value.trim().to_string());
+                    }
+                }
+                line.clear();
+            }
+            let _ = sender.send(authorization);
+            write_fixture(stream, "tests/fixtures/accented_name.html");
+        }
+    });
+
+    let mut client = ScholarClient::new(FetchOptions {
+        proxy: Some(format!("http://{address}")),
+        cors_proxy: Some("http://scholar.invalid/?quest=".to_string()),
+        http_client: Some(reqwest::Client::new()),
+        retry: RetryPolicy {
+            max_retries: 0,
+            ..RetryPolicy::default()
+        },
+        timeout: Some(Duration::from_secs(10)),
+        ..FetchOptions::default()
+    });
+    client.rotate_identity().unwrap();
+    let _ = block_on(fetch_author_info(EXAMPLE_AUTHOR_ID, client.options()));
+
+    let authorization = credentials
+        .recv_timeout(Duration::from_secs(10))
+        .expect("the request bypassed the proxy");
+    assert!(authorization.is_some_and(|value| value.starts_with("Basic ")));
+}
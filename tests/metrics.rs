@@ -0,0 +1,51 @@
+use leptos_scholar::{compute_g_index, compute_h_index, compute_i_index};
+use proptest::prelude::*;
+
+fn citation_vectors() -> impl Strategy<Value = Vec<usize>> {
+    prop::collection::vec(0usize..2000, 0..200)
+}
+
+proptest! {
+    #[test]
+    fn h_index_never_exceeds_paper_count(citations in citation_vectors()) {
+        prop_assert!(compute_h_index(&citations) <= citations.len());
+    }
+
+    #[test]
+    fn h_index_papers_have_enough_citations(citations in citation_vectors()) {
+        let h = compute_h_index(&citations);
+        prop_assert!(compute_i_index(&citations, h) >= h);
+        prop_assert!(compute_i_index(&citations, h + 1) < h + 1);
+    }
+
+    #[test]
+    fn g_index_is_at_least_h_index(citations in citation_vectors()) {
+        let g = compute_g_index(&citations);
+        prop_assert!(g >= compute_h_index(&citations));
+        prop_assert!(g <= citations.len());
+    }
+
+    #[test]
+    fn i_index_is_monotonic_in_threshold(citations in citation_vectors(), low in 0usize..500, step in 0usize..500) {
+        prop_assert!(compute_i_index(&citations, 100) <= compute_i_index(&citations, 10));
+        prop_assert!(compute_i_index(&citations, low + step) <= compute_i_index(&citations, low));
+    }
+
+    #[test]
+    fn indices_ignore_paper_order(mut citations in citation_vectors()) {
+        let (h, g) = (compute_h_index(&citations), compute_g_index(&citations));
+        citations.reverse();
+        prop_assert_eq!(compute_h_index(&citations), h);
+        prop_assert_eq!(compute_g_index(&citations), g);
+    }
+}
+
+#[test]
+fn known_values() {
+    let citations = [10, 8, 5, 4, 3];
+    assert_eq!(compute_h_index(&citations), 4);
+    assert_eq!(compute_g_index(&citations), 5);
+    assert_eq!(compute_i_index(&citations, 10), 1);
+    assert_eq!(compute_h_index(&[]), 0);
+    assert_eq!(compute_g_index(&[0, 0]), 0);
+}
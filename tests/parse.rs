@@ -0,0 +1,313 @@
+use leptos_scholar::{
+    coauthor_graph_to_dot, parse_author_info, parse_author_page, parse_citations, profile_url,
+    publications_url, AuthorInfo, CoauthorGraph, FetchOptions, GraphNode, NameFilter, OutputFormat,
+    ParseMode, PublicationDate, PublicationSort, ScraperError, DEFAULT_MAX_HTML_BYTES,
+    EXAMPLE_AUTHOR_ID,
+};
+use scraper::Html;
+
+fn parse_fixture(html: &str) -> AuthorInfo {
+    parse_author_info(&Html::parse_document(html), &FetchOptions::default()).unwrap()
+}
+
+#[test]
+fn accented_name_is_decoded() {
+    let info = parse_fixture(include_str!("fixtures/accented_name.html"));
+    assert_eq!(info.name, "José María Núñez");
+}
+
+#[test]
+fn unverified_profile_is_flagged() {
+    let info = parse_fixture(include_str!("fixtures/unverified_profile.html"));
+    assert_eq!(info.verified_email_domains, ["example.edu"]);
+    assert!(!info.verified);
+
+    // Without the disclaimer, the verified email is enough
+    let html = include_str!("fixtures/unverified_profile.html").replace("gs_alrt", "gs_note");
+    assert!(parse_fixture(&html).verified);
+    // Without an email, the profile isn't verified either
+    assert!(!parse_fixture(include_str!("fixtures/accented_name.html")).verified);
+}
+
+#[test]
+fn cjk_name_is_decoded() {
+    let info = parse_fixture(include_str!("fixtures/cjk_name.html"));
+    assert_eq!(info.name, "王小明");
+    assert_eq!(info.total, 42);
+}
+
+#[test]
+fn yaml_special_characters_in_name_round_trip() {
+    let html = include_str!("fixtures/accented_name.html").replace(
+        "<div id=\"gsc_prf_in\">Jos&#xe9; Mar&iacute;a N&uacute;&ntilde;ez</div>",
+        "<div id=\"gsc_prf_in\">- Doe: J. # &amp; [lab] {x}</div>",
+    );
+    let info = parse_fixture(&html);
+    assert_eq!(info.name, "- Doe: J. # & [lab] {x}");
+
+    let yaml = OutputFormat::Yaml.serialize(&info).unwrap();
+    let parsed: AuthorInfo = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(parsed.name, info.name);
+}
+
+#[test]
+fn reordered_summary_is_mapped_by_label() {
+    // "h-index" and "Citations" swapped, "i10" instead of "i10-index"
+    let info = parse_fixture(include_str!("fixtures/reordered_summary.html"));
+    assert_eq!((info.total, info.h_index, info.i10_index), (42, 3, 1));
+}
+
+#[test]
+fn badge_is_dropped_from_name() {
+    let html = include_str!("fixtures/badge_name.html");
+    assert_eq!(parse_fixture(html).name, "Jane Doe");
+
+    let split = html.replace("Jane Doe<span", "<span>Jane</span><span>Doe</span><span");
+    assert_eq!(parse_fixture(&split).name, "Jane Doe");
+}
+
+#[test]
+fn name_selector_and_filter() {
+    let html = include_str!("fixtures/badge_name.html")
+        .replace("gsc_prf_in", "gsc_prf_name")
+        .replace("Jane Doe<", "Jane Doe, PhD<");
+    let options = FetchOptions {
+        name_selector: Some("div#gsc_prf_name".to_string()),
+        name_filter: Some(NameFilter::new(|name| {
+            name.trim_end_matches(", PhD").to_string()
+        })),
+        ..FetchOptions::default()
+    };
+    let info = parse_author_info(&Html::parse_document(&html), &options).unwrap();
+    assert_eq!(info.name, "Jane Doe");
+}
+
+#[test]
+fn robot_check_is_rate_limited() {
+    let document = Html::parse_document(include_str!("fixtures/robot_check.html"));
+    let error = parse_author_info(&document, &FetchOptions::default()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ScraperError>(),
+        Some(&ScraperError::RateLimited)
+    );
+}
+
+#[test]
+fn name_with_robot_check_words_is_kept() {
+    for name in ["Sorrell Verify", "Ana Captchadze", "Sorry Lee"] {
+        let html = include_str!("fixtures/badge_name.html").replace("Jane Doe", name);
+        assert_eq!(parse_fixture(&html).name, name);
+    }
+}
+
+#[test]
+fn affiliation_is_extracted() {
+    let info = parse_fixture(include_str!("fixtures/unverified_profile.html"));
+    // Not the email line, which has the same class
+    assert_eq!(info.affiliation.as_deref(), Some("Example University"));
+
+    let info = parse_fixture(include_str!("fixtures/accented_name.html"));
+    assert_eq!(info.affiliation.as_deref(), Some("Universidad de Málaga"));
+}
+
+#[test]
+fn single_column_summary_has_no_recent_values() {
+    let info = parse_fixture(include_str!("fixtures/single_column_summary.html"));
+    assert_eq!((info.total, info.h_index, info.i10_index), (1234, 15, 21));
+    assert_eq!(info.raw_metrics.len(), 3);
+    assert!(info
+        .raw_metrics
+        .values()
+        .all(|(_, recent)| recent.is_none()));
+}
+
+#[test]
+fn histogram_counts_from_attributes() {
+    // No count spans, or empty ones: the counts are in `title` and `aria-label`
+    let info = parse_fixture(include_str!("fixtures/attribute_histogram.html"));
+    assert_eq!(
+        info.yearly_citations,
+        [(2021, 120), (2022, 1230), (2023, 300)].into()
+    );
+}
+
+#[test]
+fn two_email_domains() {
+    let html = include_str!("fixtures/two_email_domains.html");
+    for separator in [" and ", ", "] {
+        let info = parse_fixture(&html.replace(" and ", separator));
+        assert_eq!(
+            info.verified_email_domains,
+            ["cs.example.edu", "lab.example.org"]
+        );
+        assert!(info.verified);
+        assert_eq!(
+            info.homepage.as_ref().map(|url| url.as_str()),
+            Some("https://jane.example.org/")
+        );
+    }
+}
+
+#[test]
+fn aria_labels_are_preferred() {
+    // The bars have no year spans and empty counts, only their labels
+    let yearly = parse_citations(include_str!("fixtures/aria_histogram.html")).unwrap();
+    assert_eq!(yearly, [(2021, 58), (2022, 1), (2023, 1190)].into());
+}
+
+#[test]
+fn mobile_layout() {
+    let info = parse_fixture(include_str!("fixtures/mobile_profile.html"));
+    assert_eq!(info.name, "Jane Doe");
+    assert_eq!((info.total, info.h_index, info.i10_index), (1234, 15, 20));
+    assert_eq!(info.raw_metrics["Citations"], (1234, Some(567)));
+    assert_eq!(
+        info.yearly_citations,
+        [(2022, 150), (2023, 210), (2024, 1005)].into()
+    );
+}
+
+#[test]
+fn widget_histogram_layout() {
+    let yearly = parse_citations(include_str!("fixtures/widget_histogram.html")).unwrap();
+    assert_eq!(yearly, [(2021, 58), (2022, 117), (2023, 190)].into());
+}
+
+#[test]
+fn coauthor_cards_have_photo_and_title() {
+    let info = parse_fixture(include_str!("fixtures/coauthor_cards.html"));
+    let [poe, roe] = info.coauthors.as_slice() else {
+        panic!("expected two co-authors, got {:?}", info.coauthors);
+    };
+
+    assert_eq!(roe.name, "John Roe");
+    assert_eq!(
+        roe.affiliation.as_deref(),
+        Some("Professor of Physics, Example Institute")
+    );
+    assert_eq!(
+        roe.photo_url.as_ref().map(|url| url.as_str()),
+        Some("https://scholar.google.com/citations?view_op=small_photo&user=ROE123AAAAJ&citpid=2")
+    );
+    // The placeholder avatar isn't a photo
+    assert_eq!(poe.name, "Ann Poe");
+    assert_eq!(poe.photo_url, None);
+}
+
+#[test]
+fn profile_url_of_example_author() {
+    assert_eq!(
+        profile_url(EXAMPLE_AUTHOR_ID),
+        format!("https://scholar.google.com/citations?user={EXAMPLE_AUTHOR_ID}&hl=en")
+    );
+}
+
+#[test]
+fn publications_url_sets_sort_order() {
+    let profile = profile_url(EXAMPLE_AUTHOR_ID);
+    assert_eq!(
+        publications_url(EXAMPLE_AUTHOR_ID, 100, PublicationSort::Citations),
+        format!("{profile}&cstart=100&pagesize=100")
+    );
+    assert_eq!(
+        publications_url(EXAMPLE_AUTHOR_ID, 0, PublicationSort::Date),
+        format!("{profile}&cstart=0&pagesize=100&sortby=pubdate")
+    );
+    assert_eq!(
+        FetchOptions::default().publication_sort,
+        PublicationSort::Citations
+    );
+}
+
+#[test]
+fn oversized_response_is_rejected() {
+    let html = include_str!("fixtures/accented_name.html");
+    let oversized = format!("{html}<!--{}-->", "x".repeat(DEFAULT_MAX_HTML_BYTES));
+
+    let error = parse_author_page(&oversized, &FetchOptions::default()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref(),
+        Some(&ScraperError::ResponseTooLarge(oversized.len()))
+    );
+    assert!(parse_author_page(html, &FetchOptions::default()).is_ok());
+}
+
+#[test]
+fn lenient_parse_reports_warnings() {
+    let html = include_str!("fixtures/accented_name.html").replace("gsc_rsb_st", "gsc_rsb_gone");
+    let options = FetchOptions {
+        parse_mode: ParseMode::Lenient,
+        ..FetchOptions::default()
+    };
+    let info = parse_author_page(&html, &options).unwrap();
+    assert_eq!(info.total, 0);
+    assert!(info
+        .warnings
+        .iter()
+        .any(|warning| warning.contains("summary table")));
+    assert!(info
+        .warnings
+        .iter()
+        .all(|warning| !warning.contains("histogram")));
+
+    // Strict parsing fails instead, and a complete page has no warnings
+    assert!(parse_author_page(&html, &FetchOptions::default()).is_err());
+    assert!(parse_fixture(include_str!("fixtures/accented_name.html"))
+        .warnings
+        .is_empty());
+}
+
+#[test]
+fn coauthor_graph_exports_to_dot() {
+    let mut graph = CoauthorGraph::default();
+    let node = |name: &str, h_index| GraphNode {
+        name: name.to_string(),
+        h_index,
+    };
+    graph
+        .nodes
+        .insert("AAA".into(), node("Jane \"JD\" Doe", Some(16)));
+    graph.nodes.insert("BBB".into(), node("John Roe", None));
+    graph.edges.insert(("AAA".into(), "BBB".into()));
+
+    let dot = coauthor_graph_to_dot(&graph);
+    assert!(dot.starts_with("graph coauthors {"));
+    assert!(dot.contains(r#""AAA" [label="Jane \"JD\" Doe", width=1.75];"#));
+    assert!(dot.contains(r#""BBB" [label="John Roe"];"#));
+    assert!(dot.contains(r#""AAA" -- "BBB";"#));
+}
+
+#[test]
+fn publication_dates() {
+    let date = |year, month, day| Some(PublicationDate { year, month, day });
+
+    assert_eq!(
+        PublicationDate::parse("2020/3/15"),
+        date(2020, Some(3), Some(15))
+    );
+    assert_eq!(
+        PublicationDate::parse("15 Mar 2020"),
+        date(2020, Some(3), Some(15))
+    );
+    assert_eq!(
+        PublicationDate::parse("Mar 15, 2020"),
+        date(2020, Some(3), Some(15))
+    );
+    assert_eq!(
+        PublicationDate::parse("Mar 2020"),
+        date(2020, Some(3), None)
+    );
+    assert_eq!(PublicationDate::parse("2020"), date(2020, None, None));
+
+    // An impossible month or day is dropped on purpose, the year is kept
+    assert_eq!(PublicationDate::parse("2020/13"), date(2020, None, None));
+    assert_eq!(
+        PublicationDate::parse("2020/2/32"),
+        date(2020, Some(2), None)
+    );
+    assert_eq!(PublicationDate::parse("2020/13/5"), date(2020, None, None));
+
+    for invalid in ["", "March", "20/3/15", "99999", "n.d."] {
+        assert_eq!(PublicationDate::parse(invalid), None, "{invalid:?}");
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use leptos_scholar::{FetchOptions, RateLimiter, ScholarClient};
+use std::time::{Duration, Instant};
+
+/// Spacing of the limiter under test, short enough to keep the test fast
+const INTERVAL: Duration = Duration::from_millis(50);
+
+/// Slack for timer and clock granularity
+const TOLERANCE: Duration = Duration::from_millis(5);
+
+#[test]
+fn clones_share_one_limit() {
+    let client = ScholarClient::new(FetchOptions {
+        politeness_delay: INTERVAL,
+        ..FetchOptions::default()
+    });
+    let rate_limiter = client.options().rate_limiter.clone().unwrap();
+    assert_eq!(rate_limiter.interval(), INTERVAL);
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .build()
+        .unwrap();
+    let mut times: Vec<Instant> = runtime.block_on(async {
+        let tasks: Vec<_> = (0..5)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    let rate_limiter: &RateLimiter =
+                        client.options().rate_limiter.as_ref().unwrap();
+                    rate_limiter.acquire().await;
+                    Instant::now()
+                })
+            })
+            .collect();
+
+        let mut times = Vec::new();
+        for task in tasks {
+            times.push(task.await.unwrap());
+        }
+        times
+    });
+
+    times.sort();
+    for pair in times.windows(2) {
+        let gap = pair[1] - pair[0];
+        assert!(gap + TOLERANCE >= INTERVAL, "requests only {gap:?} apart");
+    }
+    assert!(times[4] - times[0] + TOLERANCE >= INTERVAL * 4);
+}
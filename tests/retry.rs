@@ -0,0 +1,57 @@
+use leptos_scholar::{retry_after, RetryPolicy};
+use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+use std::time::{Duration, SystemTime};
+
+fn headers(retry_after: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(RETRY_AFTER, HeaderValue::from_str(retry_after).unwrap());
+    headers
+}
+
+#[test]
+fn retry_after_in_seconds() {
+    assert_eq!(retry_after(&headers("120")), Some(Duration::from_secs(120)));
+    assert_eq!(retry_after(&headers(" 0 ")), Some(Duration::ZERO));
+    assert_eq!(retry_after(&headers("soon")), None);
+    assert_eq!(retry_after(&HeaderMap::new()), None);
+}
+
+#[test]
+fn retry_after_as_http_date() {
+    let in_two_minutes = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(120));
+    let wait = retry_after(&headers(&in_two_minutes)).unwrap();
+    // The date has whole seconds, and a moment passed since it was formatted
+    assert!(
+        wait > Duration::from_secs(115) && wait <= Duration::from_secs(120),
+        "{wait:?}"
+    );
+
+    assert_eq!(
+        retry_after(&headers("Wed, 21 Oct 2015 07:28:00 GMT")),
+        Some(Duration::ZERO)
+    );
+}
+
+#[test]
+fn delay_is_capped_at_max_delay() {
+    let policy = RetryPolicy {
+        max_retries: 10,
+        base_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(10),
+    };
+
+    assert_eq!(policy.delay(0, None), Duration::from_secs(1));
+    assert_eq!(policy.delay(3, None), Duration::from_secs(8));
+    assert_eq!(policy.delay(4, None), Duration::from_secs(10));
+    assert_eq!(policy.delay(200, None), Duration::from_secs(10));
+
+    // A requested wait replaces the backoff, but not the cap
+    assert_eq!(
+        policy.delay(3, Some(Duration::from_secs(2))),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        policy.delay(0, Some(Duration::from_secs(3600))),
+        Duration::from_secs(10)
+    );
+}
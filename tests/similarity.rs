@@ -0,0 +1,42 @@
+use leptos_scholar::{likely_same, parse_author_info, similarity, AuthorInfo, FetchOptions};
+use scraper::Html;
+
+fn parse_fixture(html: &str) -> AuthorInfo {
+    parse_author_info(&Html::parse_document(html), &FetchOptions::default()).unwrap()
+}
+
+#[test]
+fn identical_profiles_score_one() {
+    let info = parse_fixture(include_str!("fixtures/unverified_profile.html"));
+    assert_eq!(similarity(&info, &info), 1.0);
+    assert!(likely_same(&info, &info, 0.99));
+}
+
+#[test]
+fn affiliation_is_compared() {
+    let info = parse_fixture(include_str!("fixtures/mobile_profile.html"));
+    let mut moved = info.clone();
+    moved.affiliation = Some("Another Institute".to_string());
+    let mut spelled = info.clone();
+    spelled.affiliation = Some("example university,".to_string());
+
+    assert!(similarity(&info, &moved) < similarity(&info, &spelled));
+    assert_eq!(similarity(&info, &spelled), 1.0);
+}
+
+#[test]
+fn missing_affiliation_on_both_is_left_out() {
+    let mut a = parse_fixture(include_str!("fixtures/mobile_profile.html"));
+    a.affiliation = None;
+    a.coauthors.clear();
+    let mut b = a.clone();
+    b.name = "Jane Q. Doe".to_string();
+
+    let with_one = {
+        let mut b = b.clone();
+        b.affiliation = Some("Example University".to_string());
+        similarity(&a, &b)
+    };
+    assert!(similarity(&a, &b) > with_one);
+    assert!(likely_same(&a, &b, 0.7));
+}
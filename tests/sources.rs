@@ -0,0 +1,99 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use leptos_scholar::{
+    parse_author_page, AuthorSource, FetchOptions, ScraperError, SourceChain, SourceChainError,
+    SourceFuture,
+};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+/// A source answering every ID with a fixed result, recording its calls in `calls`
+struct Stub {
+    name: &'static str,
+    fails: bool,
+    calls: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl AuthorSource for Stub {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn fetch_author<'a>(&'a self, _author_id: &'a str) -> SourceFuture<'a> {
+        Box::pin(async move {
+            self.calls.lock().unwrap().push(self.name);
+            if self.fails {
+                return Err(ScraperError::RateLimited.into());
+            }
+            parse_author_page(
+                include_str!("fixtures/accented_name.html"),
+                &FetchOptions::default(),
+            )
+        })
+    }
+}
+
+fn chain(fails: [bool; 2], calls: &Arc<Mutex<Vec<&'static str>>>) -> SourceChain {
+    let [first, second] = fails;
+    SourceChain::new()
+        .with_source(Stub {
+            name: "first",
+            fails: first,
+            calls: calls.clone(),
+        })
+        .with_source(Stub {
+            name: "second",
+            fails: second,
+            calls: calls.clone(),
+        })
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn falls_back_in_order() {
+    let calls = Arc::default();
+    let sourced = block_on(chain([true, false], &calls).fetch_author("H7sOPf8AAAAJ")).unwrap();
+    assert_eq!(sourced.source, "second");
+    assert_eq!(sourced.author_info.total, 42);
+    assert_eq!(*calls.lock().unwrap(), ["first", "second"]);
+
+    // A source that succeeds ends the chain
+    let calls = Arc::default();
+    let sourced = block_on(chain([false, false], &calls).fetch_author("H7sOPf8AAAAJ")).unwrap();
+    assert_eq!(sourced.source, "first");
+    assert_eq!(*calls.lock().unwrap(), ["first"]);
+}
+
+#[test]
+fn errors_of_all_sources_are_combined() {
+    let calls = Arc::default();
+    let error = block_on(chain([true, true], &calls).fetch_author("H7sOPf8AAAAJ")).unwrap_err();
+    let error = error.downcast_ref::<SourceChainError>().unwrap();
+
+    let names: Vec<_> = error.errors.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["first", "second"]);
+    for (_, error) in &error.errors {
+        assert_eq!(
+            error.downcast_ref::<ScraperError>(),
+            Some(&ScraperError::RateLimited)
+        );
+    }
+    assert!(error.to_string().starts_with("All sources failed: first: "));
+}
+
+#[test]
+fn chain_can_be_shared_between_threads() {
+    fn assert_send<T: Send>(_: &T) {}
+    fn assert_sync<T: Send + Sync>(_: &T) {}
+
+    let chain = Arc::new(chain([true, false], &Arc::default()));
+    assert_sync(&chain);
+    let future = chain.fetch_author("H7sOPf8AAAAJ");
+    assert_send(&future);
+}